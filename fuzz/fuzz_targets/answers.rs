@@ -0,0 +1,10 @@
+#![no_main]
+
+use advent_of_code::{examples, Answers};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Answers::parse(input);
+    let _ = Answers::parse_examples(input);
+    let _ = examples::extract(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use advent_of_code::days;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = days::find(4).unwrap().solve(input, &[]);
+});
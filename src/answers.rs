@@ -0,0 +1,321 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use toml::{
+    de::{DeTable, DeValue},
+    Table, Value,
+};
+
+use crate::{input::data_dir, Answer, Error};
+
+/// Answers recorded for the real puzzle inputs, stored next to them as `answers.toml`:
+///
+/// ```toml
+/// [day01]
+/// part1 = 1292
+/// part2 = 1262
+/// ```
+///
+/// The expected answers of a day's examples use the same layout, in `dayNN-examples.toml`, with
+/// one `[example01]`, `[example02]`... section per `dayNN-example-K.txt`.
+#[derive(Debug, PartialEq)]
+pub struct Answers {
+    section: &'static str,
+    /// The comments at the top of the file, written back in front of the answers.
+    header: String,
+    recorded: BTreeMap<(u8, u8), Answer>,
+}
+
+impl Default for Answers {
+    fn default() -> Answers {
+        Answers {
+            section: "day",
+            header: String::new(),
+            recorded: BTreeMap::new(),
+        }
+    }
+}
+
+impl Answers {
+    pub fn path() -> PathBuf {
+        data_dir().join("answers.toml")
+    }
+
+    /// Expected answers of the examples of `day`, keyed by example number instead of day.
+    pub fn examples() -> Answers {
+        Answers {
+            section: "example",
+            ..Answers::default()
+        }
+    }
+
+    pub fn examples_path(day: u8) -> PathBuf {
+        data_dir().join(format!("day{:02}-examples.toml", day))
+    }
+
+    /// Loads the answers file; a missing file just means nothing has been recorded yet.
+    pub fn load(path: &Path) -> Result<Answers, Error> {
+        Answers::default().load_into(path)
+    }
+
+    pub fn load_examples(path: &Path) -> Result<Answers, Error> {
+        Answers::examples().load_into(path)
+    }
+
+    fn load_into(self, path: &Path) -> Result<Answers, Error> {
+        match fs::read_to_string(path) {
+            Ok(text) => self
+                .parse_into(&text)
+                .map_err(|why| why.in_file(&path.display().to_string())),
+            Err(why) if why.kind() == io::ErrorKind::NotFound => Ok(self),
+            Err(source) => Err(Error::Io {
+                path: path.display().to_string(),
+                source,
+            }),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Answers, Error> {
+        Answers::default().parse_into(text)
+    }
+
+    pub fn parse_examples(text: &str) -> Result<Answers, Error> {
+        Answers::examples().parse_into(text)
+    }
+
+    fn parse_into(self, text: &str) -> Result<Answers, Error> {
+        let table = text.parse::<Table>().map_err(|why| {
+            let token = why.span().map_or(text, |span| &text[span]);
+            Error::parse(text, token, why.message())
+        })?;
+        let mut answers = self;
+        let header_len: usize = text
+            .split_inclusive('\n')
+            .take_while(|line| line.trim().is_empty() || line.trim_start().starts_with('#'))
+            .map(str::len)
+            .sum();
+        answers.header = text[..header_len].to_owned();
+        if !answers.header.is_empty() && !answers.header.ends_with('\n') {
+            answers.header.push('\n');
+        }
+        for (day_key, parts) in &table {
+            let day = day_key
+                .strip_prefix(answers.section)
+                .and_then(|number| number.parse::<u8>().ok())
+                .ok_or_else(|| {
+                    let message = format!("unknown {} `{}`", answers.section, day_key);
+                    Error::parse(text, key_token(text, &[day_key]), message)
+                })?;
+            let parts = parts.as_table().ok_or_else(|| {
+                let message = format!("`{}` should be a table", day_key);
+                Error::parse(text, key_token(text, &[day_key]), message)
+            })?;
+            for (part_key, value) in parts {
+                let part = match part_key.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    _ => {
+                        return Err(Error::parse(
+                            text,
+                            key_token(text, &[day_key, part_key]),
+                            format!("unknown part `{}.{}`", day_key, part_key),
+                        ))
+                    }
+                };
+                let answer = match value {
+                    Value::Integer(number) => Answer::Number(*number as i128),
+                    // Numbers too large for TOML integers are stored as strings.
+                    Value::String(string) => match string.parse::<i128>() {
+                        Ok(number) => Answer::Number(number),
+                        Err(_) => Answer::Text(string.to_owned()),
+                    },
+                    _ => {
+                        return Err(Error::parse(
+                            text,
+                            key_token(text, &[day_key, part_key]),
+                            format!("`{}.{}` should be a number or a string", day_key, part_key),
+                        ))
+                    }
+                };
+                answers.insert(day, part, answer);
+            }
+        }
+        Ok(answers)
+    }
+
+    /// Days, or examples, with at least one answer.
+    pub fn keys(&self) -> impl Iterator<Item = (u8, u8)> + '_ {
+        self.recorded.keys().copied()
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.recorded.get(&(day, part))
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, answer: Answer) {
+        self.recorded.insert((day, part), answer);
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut table = Table::new();
+        for ((day, part), answer) in &self.recorded {
+            let parts = table
+                .entry(format!("{}{:02}", self.section, day))
+                .or_insert_with(|| Value::Table(Table::new()))
+                .as_table_mut()
+                .expect("sections are always tables");
+            let value = match answer {
+                Answer::Number(number) => match i64::try_from(*number) {
+                    Ok(number) => Value::Integer(number),
+                    Err(_) => Value::String(number.to_string()),
+                },
+                Answer::Text(text) | Answer::Picture(text) => Value::String(text.to_owned()),
+            };
+            parts.insert(format!("part{}", part), value);
+        }
+        format!("{}{}", self.header, table)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        fs::write(path, self.to_toml()).map_err(|source| Error::Io {
+            path: path.display().to_string(),
+            source,
+        })
+    }
+}
+
+/// Outcome of checking one answer against the recorded one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Mismatch { expected: Answer },
+    Unrecorded,
+}
+
+impl Answers {
+    pub fn verify(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            // Compare the printed forms, so numbers recorded as strings still match.
+            Some(expected) if expected.to_string() == answer.to_string() => Verdict::Correct,
+            Some(expected) => Verdict::Mismatch {
+                expected: expected.clone(),
+            },
+            None => Verdict::Unrecorded,
+        }
+    }
+}
+
+/// Where the key at `path`, like `["day01", "part1"]`, is written in `text`, so that errors point
+/// at it instead of at the whole file.
+fn key_token<'a>(text: &'a str, path: &[&str]) -> &'a str {
+    let document = DeTable::parse(text).ok();
+    let mut table = document.as_ref().map(|document| document.get_ref());
+    let mut span = None;
+    for key in path {
+        let entry = table.and_then(|table| table.iter().find(|(name, _)| name.get_ref() == key));
+        span = entry.map(|(name, _)| name.span());
+        table = entry.and_then(|(_, value)| match value.get_ref() {
+            DeValue::Table(table) => Some(table),
+            _ => None,
+        });
+    }
+    span.map_or(text, |span| &text[span])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Answer;
+
+    use super::{Answers, Verdict};
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse(
+            "[day06]
+            part1 = 5934
+            part2 = \"26984457539\"
+
+            [day13]
+            part2 = \"#####\\n#   #\\n\"",
+        )
+        .unwrap();
+        assert_eq!(Some(&Answer::Number(5934)), answers.get(6, 1));
+        assert_eq!(
+            Verdict::Correct,
+            answers.verify(6, 2, &Answer::Number(26984457539))
+        );
+        assert_eq!(
+            Verdict::Mismatch {
+                expected: Answer::Number(5934)
+            },
+            answers.verify(6, 1, &Answer::Number(26))
+        );
+        assert_eq!(
+            Verdict::Unrecorded,
+            answers.verify(13, 1, &Answer::Number(17))
+        );
+        assert_eq!(
+            Verdict::Correct,
+            answers.verify(13, 2, &Answer::Text("#####\n#   #\n".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_answers() {
+        let error = |text| Answers::parse(text).unwrap_err().to_string();
+        assert_eq!(
+            "2:1: unknown part `day06.part3`, found `part3`",
+            error("[day06]\npart3 = 1")
+        );
+        assert_eq!(
+            "1:2: unknown day `sixth`, found `sixth`",
+            error("[sixth]\npart1 = 1")
+        );
+        assert_eq!(
+            "3:1: `day06.part1` should be a number or a string, found `part1`",
+            error("[day06]\npart2 = 2\npart1 = 1.5")
+        );
+        assert_eq!(
+            "1:1: `day06` should be a table, found `day06`",
+            error("day06 = 6")
+        );
+        assert!(Answers::parse("[day06\npart1 = 1").is_err());
+    }
+
+    #[test]
+    fn test_answers_round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(1, 1, Answer::Number(7));
+        answers.insert(1, 2, Answer::Number(5));
+        answers.insert(13, 2, Answer::Text("#\n #\n".to_owned()));
+        answers.insert(16, 2, Answer::Number(i128::from(u64::MAX)));
+        assert_eq!(answers, Answers::parse(&answers.to_toml()).unwrap());
+    }
+
+    #[test]
+    fn test_header_round_trip() {
+        let text = "# Checked by `aoc verify`.\n\n[day01]\npart1 = 7\n";
+        let mut answers = Answers::parse(text).unwrap();
+        assert_eq!(text, answers.to_toml());
+        answers.insert(2, 1, Answer::Number(150));
+        let saved = answers.to_toml();
+        assert!(saved.starts_with("# Checked by `aoc verify`.\n\n[day01]"));
+        assert_eq!(answers, Answers::parse(&saved).unwrap());
+        let header_only = "# Nothing recorded yet.\n";
+        assert_eq!(header_only, Answers::parse(header_only).unwrap().to_toml());
+    }
+
+    #[test]
+    fn test_examples_round_trip() {
+        let mut examples = Answers::examples();
+        examples.insert(1, 1, Answer::Number(5934));
+        examples.insert(2, 2, Answer::Number(26));
+        let text = examples.to_toml();
+        assert!(text.starts_with("[example01]\npart1 = 5934\n"));
+        assert_eq!(examples, Answers::parse_examples(&text).unwrap());
+        assert!(Answers::parse(&text).is_err());
+    }
+}
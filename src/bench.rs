@@ -0,0 +1,213 @@
+use std::{fmt, str::FromStr, time::Duration};
+
+use serde_json::json;
+
+use crate::{days::Day, ParseResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part1"),
+            Stage::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty(), "Can't summarize zero samples");
+        samples.sort();
+        let middle = samples.len() / 2;
+        let median = match samples.len() % 2 {
+            0 => (samples[middle - 1] + samples[middle]) / 2,
+            _ => samples[middle],
+        };
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub iterations: usize,
+    pub stats: Stats,
+}
+
+/// Solves `input` `iterations` times and summarizes how long parsing and each part took.
+pub fn run(day: &Day, input: &str, iterations: usize) -> ParseResult<Vec<Measurement>> {
+    let mut samples = [vec![], vec![], vec![]];
+    for _ in 0..iterations {
+        let timed = day.solve_timed(input, &[1, 2])?;
+        samples[0].push(timed.parse);
+        for (index, (_, elapsed)) in timed.answers.into_iter().enumerate() {
+            samples[index + 1].push(elapsed);
+        }
+    }
+    Ok([Stage::Parse, Stage::Part1, Stage::Part2]
+        .into_iter()
+        .zip(samples)
+        .map(|(stage, samples)| Measurement {
+            day: day.number,
+            stage,
+            iterations,
+            stats: Stats::from_samples(samples),
+        })
+        .collect())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Format, String> {
+        match format {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown format `{}`", format)),
+        }
+    }
+}
+
+/// Renders measurements for people (`Table`) or for comparing runs across commits (`Json`,
+/// `Csv`, with durations in nanoseconds).
+pub fn report(measurements: &[Measurement], format: Format) -> String {
+    let nanos = |duration: Duration| duration.as_nanos() as u64;
+    match format {
+        Format::Table => {
+            let mut table = format!(
+                "{:<4} {:<6} {:>12} {:>12} {:>12}\n",
+                "day", "stage", "min", "median", "max"
+            );
+            for measurement in measurements {
+                let Stats { min, median, max } = measurement.stats;
+                table += &format!(
+                    "{:<4} {:<6} {:>12} {:>12} {:>12}\n",
+                    measurement.day,
+                    measurement.stage.to_string(),
+                    format!("{:.2?}", min),
+                    format!("{:.2?}", median),
+                    format!("{:.2?}", max)
+                );
+            }
+            table
+        }
+        Format::Json => {
+            let records: Vec<_> = measurements
+                .iter()
+                .map(|measurement| {
+                    json!({
+                        "day": measurement.day,
+                        "stage": measurement.stage.to_string(),
+                        "iterations": measurement.iterations,
+                        "min_ns": nanos(measurement.stats.min),
+                        "median_ns": nanos(measurement.stats.median),
+                        "max_ns": nanos(measurement.stats.max),
+                    })
+                })
+                .collect();
+            serde_json::to_string_pretty(&records).expect("records are plain values") + "\n"
+        }
+        Format::Csv => {
+            let mut csv = "day,stage,iterations,min_ns,median_ns,max_ns\n".to_owned();
+            for measurement in measurements {
+                csv += &format!(
+                    "{},{},{},{},{},{}\n",
+                    measurement.day,
+                    measurement.stage,
+                    measurement.iterations,
+                    nanos(measurement.stats.min),
+                    nanos(measurement.stats.median),
+                    nanos(measurement.stats.max)
+                );
+            }
+            csv
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::days;
+
+    use super::{report, run, Format, Measurement, Stage, Stats};
+
+    #[test]
+    fn test_stats() {
+        let millis = |values: &[u64]| values.iter().map(|&ms| Duration::from_millis(ms)).collect();
+        let stats = Stats::from_samples(millis(&[5, 1, 3]));
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(3), stats.median);
+        assert_eq!(Duration::from_millis(5), stats.max);
+        assert_eq!(
+            Duration::from_millis(3),
+            Stats::from_samples(millis(&[4, 1, 2, 9])).median
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let measurements = run(days::find(6).unwrap(), "3,4,3,1,2", 3).unwrap();
+        let stages: Vec<_> = measurements
+            .iter()
+            .map(|measurement| measurement.stage)
+            .collect();
+        assert_eq!(vec![Stage::Parse, Stage::Part1, Stage::Part2], stages);
+        assert!(measurements
+            .iter()
+            .all(|measurement| measurement.iterations == 3));
+        assert!(run(days::find(6).unwrap(), "3,x", 3).is_err());
+    }
+
+    #[test]
+    fn test_report() {
+        let stats = Stats {
+            min: Duration::from_nanos(10),
+            median: Duration::from_nanos(20),
+            max: Duration::from_nanos(40),
+        };
+        let measurements = [Measurement {
+            day: 15,
+            stage: Stage::Part2,
+            iterations: 5,
+            stats,
+        }];
+        assert_eq!(
+            "day,stage,iterations,min_ns,median_ns,max_ns\n15,part2,5,10,20,40\n",
+            report(&measurements, Format::Csv)
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&report(&measurements, Format::Json)).unwrap();
+        assert_eq!(20, json[0]["median_ns"]);
+        assert_eq!("part2", json[0]["stage"]);
+        assert!(report(&measurements, Format::Table).contains("20.00ns"));
+    }
+}
@@ -0,0 +1,299 @@
+use std::{
+    any::Any,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+use advent_of_code::{answers::Verdict, days, Answer, Answers, InputSource};
+use serde_json::json;
+
+use crate::{record, with_export, Format, Options};
+
+#[derive(Debug, PartialEq)]
+enum Status {
+    Ok,
+    Unrecorded,
+    Mismatch(Answer),
+    Failed(String),
+    Panicked(String),
+}
+
+impl Status {
+    fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Status::Mismatch(_) | Status::Failed(_) | Status::Panicked(_)
+        )
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Unrecorded => write!(f, "unrecorded"),
+            Status::Mismatch(expected) => write!(f, "MISMATCH, expected {}", summary(expected)),
+            Status::Failed(why) => write!(f, "FAILED: {}", why),
+            Status::Panicked(why) => write!(f, "PANICKED: {}", why),
+        }
+    }
+}
+
+struct Outcome {
+    day: u8,
+    part: u8,
+    answer: Option<(Answer, Duration)>,
+    status: Status,
+}
+
+/// Runs `job` on every item with `workers` threads, returning the results in the items' order.
+fn run_pool<T: Sync, R: Send>(items: &[T], workers: usize, job: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers.max(1))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = vec![];
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        match items.get(index) {
+                            Some(item) => done.push((index, job(item))),
+                            None => return done,
+                        }
+                    }
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("jobs catch their own panics"))
+            .collect()
+    });
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_owned(),
+        },
+    }
+}
+
+/// Answers drawn over several lines are shown by their letters in the table.
+fn summary(answer: &Answer) -> String {
+    match (answer.decode(), answer.to_string().contains('\n')) {
+        (Some(letters), _) => letters,
+        (None, true) => "[picture]".to_owned(),
+        (None, false) => answer.to_string(),
+    }
+}
+
+/// Solves every selected day and part in parallel, then reports all of them at once. Answers
+/// read from the data folder are checked against the recorded ones.
+pub fn run_all(options: &Options) -> bool {
+    let recorded = match options.input {
+        None => match Answers::load(&Answers::path()) {
+            Ok(answers) => answers,
+            Err(why) => {
+                eprintln!("{}", why);
+                return false;
+            }
+        },
+        Some(_) => Answers::default(),
+    };
+    let inputs: Vec<_> = options
+        .days
+        .iter()
+        .map(|number| {
+            let day = days::find(*number).expect("days are validated while parsing arguments");
+            let source = InputSource::resolve(options.input.as_deref(), &day.name());
+            (day, source.read(), source)
+        })
+        .collect();
+    let jobs: Vec<_> = inputs
+        .iter()
+        .flat_map(|input| options.parts.iter().map(move |part| (input, *part)))
+        .collect();
+    let workers = options
+        .jobs
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, |workers| workers.get()));
+
+    let outcomes = run_pool(&jobs, workers, |((day, input, source), part)| {
+        let input = match input {
+            Ok(input) => input,
+            Err(why) => {
+                return Outcome {
+                    day: day.number,
+                    part: *part,
+                    answer: None,
+                    status: Status::Failed(why.to_string()),
+                }
+            }
+        };
+        let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+            with_export(options.export.as_deref(), || {
+                day.solve_timed(input, &[*part])
+            })
+        }));
+        let (answer, elapsed) = match solved {
+            Ok((_, Err(why))) => {
+                let status = Status::Failed(format!("Can't export pictures: {}", why));
+                return Outcome {
+                    day: day.number,
+                    part: *part,
+                    answer: None,
+                    status,
+                };
+            }
+            Ok((Ok(mut timed), _)) => timed.answers.remove(0),
+            Ok((Err(why), _)) => {
+                let status = Status::Failed(why.in_file(&source.to_string()).to_string());
+                return Outcome {
+                    day: day.number,
+                    part: *part,
+                    answer: None,
+                    status,
+                };
+            }
+            Err(payload) => {
+                let status = Status::Panicked(panic_message(payload));
+                return Outcome {
+                    day: day.number,
+                    part: *part,
+                    answer: None,
+                    status,
+                };
+            }
+        };
+        let status = match recorded.verify(day.number, *part, &answer) {
+            Verdict::Correct => Status::Ok,
+            Verdict::Mismatch { expected } => Status::Mismatch(expected),
+            Verdict::Unrecorded => Status::Unrecorded,
+        };
+        Outcome {
+            day: day.number,
+            part: *part,
+            answer: Some((answer, elapsed)),
+            status,
+        }
+    });
+
+    match options.format {
+        Format::Text => print!("{}", table(&outcomes)),
+        Format::Json => {
+            let records: Vec<_> = outcomes
+                .iter()
+                .map(|outcome| {
+                    let mut entry = match &outcome.answer {
+                        Some((answer, elapsed)) => {
+                            record(outcome.day, outcome.part, answer, *elapsed)
+                        }
+                        None => json!({ "day": outcome.day, "part": outcome.part }),
+                    };
+                    entry["status"] = json!(outcome.status.to_string());
+                    entry
+                })
+                .collect();
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&records).expect("records are plain values")
+            );
+        }
+    }
+    !outcomes.iter().any(|outcome| outcome.status.is_failure())
+}
+
+fn table(outcomes: &[Outcome]) -> String {
+    let rows: Vec<[String; 5]> = outcomes
+        .iter()
+        .map(|outcome| {
+            let (answer, elapsed) = match &outcome.answer {
+                Some((answer, elapsed)) => (summary(answer), format!("{:.2?}", elapsed)),
+                None => ("-".to_owned(), "-".to_owned()),
+            };
+            [
+                outcome.day.to_string(),
+                outcome.part.to_string(),
+                answer,
+                elapsed,
+                outcome.status.to_string(),
+            ]
+        })
+        .collect();
+    let header = ["Day", "Part", "Answer", "Time", "Status"].map(String::from);
+    let widths: Vec<usize> = (0..4)
+        .map(|column| {
+            rows.iter()
+                .chain([&header])
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap()
+        })
+        .collect();
+    let mut table = String::new();
+    for row in [&header].into_iter().chain(&rows) {
+        table += &format!(
+            "{:>w0$}  {:>w1$}  {:<w2$}  {:>w3$}  {}\n",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            row[4],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3]
+        );
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use advent_of_code::Answer;
+
+    use super::{run_pool, table, Outcome, Status};
+
+    #[test]
+    fn test_run_pool() {
+        let items: Vec<u64> = (1..=20).collect();
+        assert_eq!(
+            items.iter().map(|n| n * n).collect::<Vec<_>>(),
+            run_pool(&items, 4, |n| n * n)
+        );
+        assert_eq!(vec![2], run_pool(&[1], 0, |n| n + 1));
+    }
+
+    #[test]
+    fn test_table() {
+        let outcomes = [
+            Outcome {
+                day: 6,
+                part: 1,
+                answer: Some((Answer::Number(5934), Duration::from_micros(12))),
+                status: Status::Ok,
+            },
+            Outcome {
+                day: 13,
+                part: 2,
+                answer: None,
+                status: Status::Panicked("boom".to_owned()),
+            },
+        ];
+        let expected = [
+            "Day  Part  Answer     Time  Status",
+            "  6     1  5934    12.00µs  ok",
+            " 13     2  -             -  PANICKED: boom",
+        ];
+        assert_eq!(expected.join("\n") + "\n", table(&outcomes));
+    }
+}
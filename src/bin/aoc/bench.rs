@@ -0,0 +1,106 @@
+use std::io;
+
+use advent_of_code::{
+    bench::{self, Format},
+    days, Error, InputSource,
+};
+
+use crate::parse_days;
+
+pub const USAGE: &str = "Usage: aoc bench [DAYS] [--iterations <N>] [--format <table|json|csv>]
+
+Times parsing, part 1 and part 2 of every selected day over N runs (default 10) of its
+real input and reports min/median/max. Days whose input is missing are skipped.";
+
+#[derive(Debug, PartialEq)]
+pub struct BenchOptions {
+    pub days: Vec<u8>,
+    pub iterations: usize,
+    pub format: Format,
+}
+
+pub fn parse_bench_args(args: &[String]) -> Result<BenchOptions, String> {
+    let mut options = BenchOptions {
+        days: parse_days("all")?,
+        iterations: 10,
+        format: Format::Table,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" => {
+                let iterations = args.next().ok_or("--iterations expects a value")?;
+                options.iterations = match iterations.parse() {
+                    Ok(iterations) if iterations > 0 => iterations,
+                    _ => return Err(format!("Invalid iteration count `{}`", iterations)),
+                };
+            }
+            "--format" => {
+                options.format = args.next().ok_or("--format expects a value")?.parse()?
+            }
+            flag if flag.starts_with("--") => return Err(format!("Unknown option `{}`", flag)),
+            days => options.days = parse_days(days)?,
+        }
+    }
+    Ok(options)
+}
+
+pub fn bench(options: &BenchOptions) -> bool {
+    let mut succeeded = true;
+    let mut measurements = vec![];
+    for number in &options.days {
+        let day = days::find(*number).expect("days are validated while parsing arguments");
+        let source = InputSource::resolve(None, &day.name());
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(Error::Io { source: why, .. }) if why.kind() == io::ErrorKind::NotFound => {
+                eprintln!("Day {:02}: skipped, no input at {}", day.number, source);
+                continue;
+            }
+            Err(why) => {
+                eprintln!("Day {:02}: {}", day.number, why);
+                succeeded = false;
+                continue;
+            }
+        };
+        match bench::run(day, &input, options.iterations) {
+            Ok(results) => measurements.extend(results),
+            Err(why) => {
+                eprintln!(
+                    "Day {:02}: {}",
+                    day.number,
+                    why.in_file(&source.to_string())
+                );
+                succeeded = false;
+            }
+        }
+    }
+    print!("{}", bench::report(&measurements, options.format));
+    succeeded
+}
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code::bench::Format;
+
+    use super::{parse_bench_args, BenchOptions};
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_ascii_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_bench_args() {
+        assert_eq!(
+            BenchOptions {
+                days: vec![15, 16],
+                iterations: 100,
+                format: Format::Csv
+            },
+            parse_bench_args(&args("15-16 --iterations 100 --format csv")).unwrap()
+        );
+        assert_eq!(10, parse_bench_args(&args("")).unwrap().iterations);
+        assert!(parse_bench_args(&args("--iterations 0")).is_err());
+        assert!(parse_bench_args(&args("--format xml")).is_err());
+    }
+}
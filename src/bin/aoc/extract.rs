@@ -0,0 +1,78 @@
+use std::fs;
+
+use advent_of_code::examples;
+
+pub const USAGE: &str = "Usage: aoc extract <DAY> <PAGE.html>
+
+Reads a puzzle page saved from the browser and writes its example blocks to
+`$AOC_DATA_DIR/dayNN-example-K.txt`, and the emphasised answers of each part to
+`dayNN-examples.toml` as the expected answers of the first example. The tests run
+every example listed there.";
+
+#[derive(Debug, PartialEq)]
+pub struct ExtractOptions {
+    pub day: u8,
+    pub page: String,
+}
+
+pub fn parse_extract_args(args: &[String]) -> Result<ExtractOptions, String> {
+    match args {
+        [day, page] => match day.parse::<u8>() {
+            Ok(day) if (1..=25).contains(&day) => Ok(ExtractOptions {
+                day,
+                page: page.to_owned(),
+            }),
+            _ => Err(format!("Advent of Code has no day `{}`", day)),
+        },
+        _ => Err("extract expects a day and a saved page".to_owned()),
+    }
+}
+
+pub fn extract(options: &ExtractOptions) -> bool {
+    let html = match fs::read_to_string(&options.page) {
+        Ok(html) => html,
+        Err(why) => {
+            eprintln!("Can't open {}: {}", options.page, why);
+            return false;
+        }
+    };
+    let page = examples::extract(&html);
+    if page.examples.is_empty() {
+        eprintln!("No `<pre><code>` example in {}", options.page);
+        return false;
+    }
+    match examples::save(options.day, &page) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {}", path.display());
+            }
+            for (part, answer) in (1..=2).zip(&page.answers) {
+                println!("Part {} of example 1: {}", part, answer);
+            }
+            true
+        }
+        Err(why) => {
+            eprintln!("{}", why);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_extract_args, ExtractOptions};
+
+    #[test]
+    fn test_parse_extract_args() {
+        let args = ["6".to_owned(), "day6.html".to_owned()];
+        assert_eq!(
+            Ok(ExtractOptions {
+                day: 6,
+                page: "day6.html".to_owned()
+            }),
+            parse_extract_args(&args)
+        );
+        assert!(parse_extract_args(&args[..1]).is_err());
+        assert!(parse_extract_args(&["0".to_owned(), "day0.html".to_owned()]).is_err());
+    }
+}
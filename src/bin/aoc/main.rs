@@ -0,0 +1,407 @@
+use std::{
+    env, io,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant},
+};
+
+use advent_of_code::{days, export, visualize, Answer, Error, InputSource, Timed};
+use serde_json::json;
+
+mod all;
+mod bench;
+mod extract;
+mod repl;
+mod scaffold;
+mod verify;
+
+const USAGE: &str =
+    "Usage: aoc [DAYS] [--part <1|2>] [--input <PATH>] [--format <text|json>] [--jobs <N>]
+                [--visualize [--fps <N>]] [--export <DIR>] [--stream]
+       aoc verify [DAYS] [--record]
+       aoc bench [DAYS] [--iterations <N>] [--format <table|json|csv>]
+       aoc new-day <DAY>
+       aoc extract <DAY> <PAGE.html>
+       aoc repl [DAY]
+
+DAYS is a single day (5), an inclusive range (3-7) or `all` (default).
+Several days run in parallel on N threads (default: one per CPU) and are reported in a table
+with their time and status; a failure, a panic or an answer that differs from the one
+recorded in answers.toml makes the exit code non-zero.
+Inputs are read from `$AOC_DATA_DIR/dayNN.txt` (default `data`), `--input -` reads stdin.
+Gzip-compressed inputs are decompressed transparently.
+--format json prints a list of `{day, part, answer, elapsed}` records, elapsed in seconds.
+Answers drawn as letters come with the `picture` as well.
+--visualize animates the simulation of a single day (9, 11, 13 or 15) in the terminal at
+--fps frames per second (default 10): space pauses, n steps, +/- change speed, q skips.
+--export writes pictures of days 5, 9, 13 and 15 to DIR as PGM, PPM or SVG files.
+--stream solves a single day (1, 5 or 10) while reading its input, for inputs too big for memory.";
+
+#[derive(Debug, PartialEq)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    days: Vec<u8>,
+    parts: Vec<u8>,
+    input: Option<String>,
+    format: Format,
+    jobs: Option<usize>,
+    visualize: Option<u32>,
+    export: Option<String>,
+    stream: bool,
+}
+
+fn parse_days(arg: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |token: &str| match token.trim().parse::<u8>() {
+        Ok(number) if days::find(number).is_some() => Ok(number),
+        _ => Err(format!("No solver for day `{}`", token)),
+    };
+    match arg {
+        "all" => Ok(days::DAYS.iter().map(|day| day.number).collect()),
+        range => match range.split_once('-') {
+            Some((from, to)) => Ok((parse_day(from)?..=parse_day(to)?).collect()),
+            None => Ok(vec![parse_day(range)?]),
+        },
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        days: parse_days("all")?,
+        parts: vec![1, 2],
+        input: None,
+        format: Format::Text,
+        jobs: None,
+        visualize: None,
+        export: None,
+        stream: false,
+    };
+    let mut fps = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let part = args.next().ok_or("--part expects a value")?;
+                options.parts = match part.as_str() {
+                    "1" => vec![1],
+                    "2" => vec![2],
+                    _ => return Err(format!("Unknown part `{}`", part)),
+                };
+            }
+            "--input" => {
+                let path = args.next().ok_or("--input expects a path")?;
+                options.input = Some(path.to_owned());
+            }
+            "--format" => {
+                let format = args.next().ok_or("--format expects a value")?;
+                options.format = match format.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    _ => return Err(format!("Unknown format `{}`", format)),
+                };
+            }
+            "--jobs" => {
+                let jobs = args.next().ok_or("--jobs expects a value")?;
+                options.jobs = match jobs.parse() {
+                    Ok(jobs) if jobs > 0 => Some(jobs),
+                    _ => return Err(format!("Invalid number of jobs `{}`", jobs)),
+                };
+            }
+            "--export" => {
+                let dir = args.next().ok_or("--export expects a directory")?;
+                options.export = Some(dir.to_owned());
+            }
+            "--stream" => options.stream = true,
+            "--visualize" => options.visualize = Some(10),
+            "--fps" => {
+                let value = args.next().ok_or("--fps expects a value")?;
+                fps = match value.parse() {
+                    Ok(value) if value > 0 => Some(value),
+                    _ => return Err(format!("Invalid frame rate `{}`", value)),
+                };
+            }
+            flag if flag.starts_with("--") => return Err(format!("Unknown option `{}`", flag)),
+            days => options.days = parse_days(days)?,
+        }
+    }
+    match (options.visualize, fps) {
+        (Some(_), Some(fps)) => options.visualize = Some(fps),
+        (None, Some(_)) => return Err("--fps only applies to --visualize".to_owned()),
+        _ => {}
+    }
+    if options.visualize.is_some() && (options.days.len() > 1 || options.format == Format::Json) {
+        return Err("--visualize needs a single day and the text format".to_owned());
+    }
+    if options.days.is_empty() {
+        return Err("No days selected".to_owned());
+    }
+    if options.stream {
+        let streams = options
+            .days
+            .iter()
+            .all(|&number| days::find(number).is_some_and(|day| day.streams()));
+        if options.days.len() > 1 || !streams || options.visualize.is_some() {
+            return Err("--stream needs a single day that can stream: 1, 5 or 10".to_owned());
+        }
+    }
+    if options.input.is_some() && options.days.len() > 1 {
+        return Err("--input can only be used with a single day".to_owned());
+    }
+    Ok(options)
+}
+
+fn record(day: u8, part: u8, answer: &Answer, elapsed: Duration) -> serde_json::Value {
+    let mut record = json!({
+        "day": day,
+        "part": part,
+        "answer": answer.to_string(),
+        "elapsed": elapsed.as_secs_f64(),
+    });
+    if let Answer::Picture(picture) = answer {
+        record["picture"] = json!(picture);
+        if let Some(text) = answer.decode() {
+            record["answer"] = json!(text);
+        }
+    }
+    record
+}
+
+/// Runs `f` saving the pictures of the days to `dir`, if any.
+fn with_export<R>(dir: Option<&str>, f: impl FnOnce() -> R) -> (R, io::Result<Vec<PathBuf>>) {
+    match dir {
+        Some(dir) => export::with_dir(Path::new(dir), f),
+        None => (f(), Ok(vec![])),
+    }
+}
+
+/// Solves while reading, timing the single pass as a whole for every part.
+fn stream(day: &days::Day, source: &InputSource, parts: &[u8]) -> Result<Timed, Error> {
+    let start = Instant::now();
+    let answers = day
+        .solve_stream(source.lines()?, parts)
+        .expect("streaming days are validated while parsing arguments")
+        .map_err(|why| why.in_file(&source.to_string()))?;
+    let elapsed = start.elapsed();
+    Ok(Timed {
+        parse: Duration::ZERO,
+        answers: answers
+            .into_iter()
+            .map(|answer| (answer, elapsed))
+            .collect(),
+    })
+}
+
+fn run(options: &Options) -> bool {
+    if options.days.len() > 1 {
+        return all::run_all(options);
+    }
+    let mut succeeded = true;
+    let mut records = vec![];
+    for number in &options.days {
+        let day = days::find(*number).expect("days are validated while parsing arguments");
+        let source = InputSource::resolve(options.input.as_deref(), &day.name());
+        let solve = |input: String| {
+            let (timed, exported) = with_export(options.export.as_deref(), || {
+                day.solve_timed(&input, &options.parts)
+            });
+            match exported {
+                Ok(written) => written
+                    .iter()
+                    .for_each(|path| eprintln!("Exported {}", path.display())),
+                Err(why) => {
+                    let path = options.export.clone().unwrap_or_default();
+                    return Err(Error::Io { path, source: why });
+                }
+            }
+            timed.map_err(|why| why.in_file(&source.to_string()))
+        };
+        let solved = match options.stream {
+            true => stream(day, &source, &options.parts),
+            false => source.read().and_then(|input| match options.visualize {
+                Some(fps) => match visualize::Terminal::new(fps) {
+                    Ok(terminal) => visualize::with_sink(Box::new(terminal), || solve(input)),
+                    Err(why) => Err(Error::Io {
+                        path: "the terminal".to_owned(),
+                        source: why,
+                    }),
+                },
+                None => solve(input),
+            }),
+        };
+        let timed = match solved {
+            Ok(timed) => timed,
+            Err(why) => {
+                eprintln!("Day {:02}: {}", day.number, why);
+                succeeded = false;
+                continue;
+            }
+        };
+        for (part, (answer, elapsed)) in options.parts.iter().zip(timed.answers) {
+            match options.format {
+                Format::Json => records.push(record(day.number, *part, &answer, elapsed)),
+                Format::Text => {
+                    let answer = answer.to_string();
+                    match answer.contains('\n') {
+                        true => print!("Day {:02} part {}:\n{}", day.number, part, answer),
+                        false => println!("Day {:02} part {}: {}", day.number, part, answer),
+                    }
+                }
+            }
+        }
+    }
+    if options.format == Format::Json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&records).expect("records are plain values")
+        );
+    }
+    succeeded
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        match args.first().map(String::as_str) {
+            Some("verify") => println!("{}", verify::USAGE),
+            Some("bench") => println!("{}", bench::USAGE),
+            Some("new-day") => println!("{}", scaffold::USAGE),
+            Some("extract") => println!("{}", extract::USAGE),
+            Some("repl") => println!("{}", repl::USAGE),
+            _ => println!("{}", USAGE),
+        }
+        return;
+    }
+    let succeeded = match args.first().map(String::as_str) {
+        Some("verify") => match verify::parse_verify_args(&args[1..]) {
+            Ok(options) => verify::verify(&options),
+            Err(why) => {
+                eprintln!("{}\n\n{}", why, verify::USAGE);
+                process::exit(2);
+            }
+        },
+        Some("bench") => match bench::parse_bench_args(&args[1..]) {
+            Ok(options) => bench::bench(&options),
+            Err(why) => {
+                eprintln!("{}\n\n{}", why, bench::USAGE);
+                process::exit(2);
+            }
+        },
+        Some("new-day") => match scaffold::parse_new_day_args(&args[1..]) {
+            Ok(number) => scaffold::new_day(number),
+            Err(why) => {
+                eprintln!("{}\n\n{}", why, scaffold::USAGE);
+                process::exit(2);
+            }
+        },
+        Some("extract") => match extract::parse_extract_args(&args[1..]) {
+            Ok(options) => extract::extract(&options),
+            Err(why) => {
+                eprintln!("{}\n\n{}", why, extract::USAGE);
+                process::exit(2);
+            }
+        },
+        Some("repl") => match repl::parse_repl_args(&args[1..]) {
+            Ok(day) => repl::repl(day),
+            Err(why) => {
+                eprintln!("{}\n\n{}", why, repl::USAGE);
+                process::exit(2);
+            }
+        },
+        _ => match parse_args(&args) {
+            Ok(options) => run(&options),
+            Err(why) => {
+                eprintln!("{}\n\n{}", why, USAGE);
+                process::exit(2);
+            }
+        },
+    };
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use advent_of_code::{days, Answer};
+
+    use crate::{parse_args, parse_days, record, Format, Options};
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_ascii_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(vec![5], parse_days("5").unwrap());
+        assert_eq!(vec![3, 4, 5, 6, 7], parse_days("3-7").unwrap());
+        assert_eq!(days::DAYS.len(), parse_days("all").unwrap().len());
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("five").is_err());
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            Options {
+                days: vec![15],
+                parts: vec![2],
+                input: Some("big.txt".to_owned()),
+                format: Format::Json,
+                jobs: None,
+                visualize: None,
+                export: Some("pictures".to_owned()),
+                stream: false,
+            },
+            parse_args(&args(
+                "15 --part 2 --input big.txt --format json --export pictures"
+            ))
+            .unwrap()
+        );
+        assert_eq!(Format::Text, parse_args(&args("")).unwrap().format);
+        assert!(parse_args(&args("--format xml")).is_err());
+        assert_eq!(Some(4), parse_args(&args("all --jobs 4")).unwrap().jobs);
+        assert!(parse_args(&args("--jobs 0")).is_err());
+        assert_eq!(
+            Some(10),
+            parse_args(&args("11 --visualize")).unwrap().visualize
+        );
+        assert_eq!(
+            Some(30),
+            parse_args(&args("11 --fps 30 --visualize"))
+                .unwrap()
+                .visualize
+        );
+        assert!(parse_args(&args("11 --fps 30")).is_err());
+        assert!(parse_args(&args("9-11 --visualize")).is_err());
+        assert_eq!(vec![1, 2], parse_args(&args("")).unwrap().parts);
+        assert!(parse_args(&args("--part 3")).is_err());
+        assert!(parse_args(&args("1-3 --input big.txt")).is_err());
+        assert!(parse_args(&args("--export")).is_err());
+        assert!(parse_args(&args("10 --stream")).unwrap().stream);
+        assert!(parse_args(&args("11 --stream")).is_err());
+        assert!(parse_args(&args("1-5 --stream")).is_err());
+        assert!(parse_args(&args("--verbose")).is_err());
+    }
+
+    #[test]
+    fn test_record() {
+        let elapsed = Duration::from_millis(1500);
+        let number = record(6, 1, &Answer::Number(5934), elapsed);
+        assert_eq!(
+            r#"{"answer":"5934","day":6,"elapsed":1.5,"part":1}"#,
+            number.to_string()
+        );
+
+        let picture = "#  #\n#  #\n####\n#  #\n#  #\n#  #\n".to_owned();
+        let letters = record(13, 2, &Answer::Picture(picture.clone()), elapsed);
+        assert_eq!("H", letters["answer"]);
+        assert_eq!(picture, letters["picture"]);
+    }
+}
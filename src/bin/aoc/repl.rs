@@ -0,0 +1,322 @@
+use std::{
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+
+use advent_of_code::{
+    days::{self, day01, day04, day06, day09, day11, day12, day13, day14, day15, day16},
+    Grid, InputSource, Solution,
+};
+
+pub const USAGE: &str = "Usage: aoc repl [DAY]
+
+Starts an interactive session on a day's input, loaded from the data folder when DAY is given.
+Type `help` in the session for the commands.";
+
+const HELP: &str =
+    "load <DAY> [PATH]   load a day's input, from the data folder unless PATH is given
+solve [1|2]         solve both parts, or one
+show                print the parsed input: boards, grids, cave graph, paper or packet tree
+measure <WINDOW>    day 1: depth increases of sliding windows of that size
+fish <DAYS>         day 6: lanternfish after that many days
+flashes <STEPS>     day 11: flashes after that many steps
+fold <COUNT>        day 13: the paper after that many folds
+polymer <STEPS>     day 14: most minus least common element after that many steps
+tile <FACTOR>       day 15: lowest risk across the map repeated FACTOR times each way
+quit                leave";
+
+struct Loaded {
+    day: u8,
+    input: String,
+    source: String,
+}
+
+#[derive(Default)]
+pub struct Session {
+    loaded: Option<Loaded>,
+}
+
+pub fn parse_repl_args(args: &[String]) -> Result<Option<u8>, String> {
+    match args {
+        [] => Ok(None),
+        [day] => match day.parse::<u8>() {
+            Ok(number) if days::find(number).is_some() => Ok(Some(number)),
+            _ => Err(format!("No solver for day `{}`", day)),
+        },
+        _ => Err("repl expects at most one day".to_owned()),
+    }
+}
+
+fn argument<T: FromStr>(args: &[&str], name: &str) -> Result<T, String> {
+    match args {
+        [value] => value
+            .parse()
+            .map_err(|_| format!("Invalid {} `{}`", name, value)),
+        _ => Err(format!("Expected one {}", name)),
+    }
+}
+
+impl Session {
+    fn loaded(&self) -> Result<&Loaded, String> {
+        self.loaded
+            .as_ref()
+            .ok_or_else(|| "No input loaded, use `load <DAY>`".to_owned())
+    }
+
+    /// The loaded input parsed by day `day`.
+    fn parse<S: Solution>(&self, day: u8, command: &str) -> Result<S::Input, String> {
+        let loaded = self.loaded()?;
+        if loaded.day != day {
+            return Err(format!(
+                "`{}` is a day {} command, day {} is loaded",
+                command, day, loaded.day
+            ));
+        }
+        S::parse(&loaded.input).map_err(|why| why.in_file(&loaded.source).to_string())
+    }
+
+    fn load(&mut self, args: &[&str]) -> Result<String, String> {
+        let (day, path) = match args {
+            [day] => (day, None),
+            [day, path] => (day, Some(*path)),
+            _ => return Err("Expected a day and maybe a path".to_owned()),
+        };
+        let day = match day.parse::<u8>().ok().and_then(days::find) {
+            Some(day) => day,
+            None => return Err(format!("No solver for day `{}`", day)),
+        };
+        let source = InputSource::resolve(path, &day.name());
+        let input = source.read().map_err(|why| why.to_string())?;
+        let loaded = format!("Loaded {} ({} lines)", source, input.lines().count());
+        self.loaded = Some(Loaded {
+            day: day.number,
+            input,
+            source: source.to_string(),
+        });
+        Ok(loaded)
+    }
+
+    fn solve(&self, args: &[&str]) -> Result<String, String> {
+        let parts = match args {
+            [] => vec![1, 2],
+            _ => match argument(args, "part")? {
+                part @ (1 | 2) => vec![part],
+                part => return Err(format!("There is no part {}", part)),
+            },
+        };
+        let loaded = self.loaded()?;
+        let day = days::find(loaded.day).expect("only known days are loaded");
+        let answers = day
+            .solve(&loaded.input, &parts)
+            .map_err(|why| why.in_file(&loaded.source).to_string())?;
+        let lines: Vec<String> = parts
+            .iter()
+            .zip(answers)
+            .map(|(part, answer)| {
+                let answer = answer.to_string();
+                match answer.contains('\n') {
+                    true => format!("Part {}:\n{}", part, answer.trim_end()),
+                    false => format!("Part {}: {}", part, answer),
+                }
+            })
+            .collect();
+        Ok(lines.join("\n"))
+    }
+
+    fn show(&self) -> Result<String, String> {
+        let day = self.loaded()?.day;
+        let digits = |grid: &Grid<u8>| grid.render(|&digit| char::from(b'0' + digit));
+        match day {
+            4 => {
+                let (numbers, boards) = self.parse::<day04::Day04>(4, "show")?;
+                let numbers: Vec<String> = numbers.iter().map(u8::to_string).collect();
+                let boards: Vec<String> = boards.iter().map(|board| board.to_string()).collect();
+                Ok(format!(
+                    "Drawn: {}\n\n{}",
+                    numbers.join(","),
+                    boards.join("\n").trim_end()
+                ))
+            }
+            9 => Ok(digits(&self.parse::<day09::Day09>(9, "show")?)
+                .trim_end()
+                .to_owned()),
+            11 => Ok(digits(&self.parse::<day11::Day11>(11, "show")?)
+                .trim_end()
+                .to_owned()),
+            15 => Ok(digits(&self.parse::<day15::Day15>(15, "show")?)
+                .trim_end()
+                .to_owned()),
+            12 => Ok(self
+                .parse::<day12::Day12>(12, "show")?
+                .to_string()
+                .trim_end()
+                .to_owned()),
+            13 => {
+                let paper = self.parse::<day13::Day13>(13, "show")?;
+                Ok(format!(
+                    "{} dots\n{}",
+                    paper.dot_count(),
+                    paper.to_string().trim_end()
+                ))
+            }
+            16 => Ok(self
+                .parse::<day16::Day16>(16, "show")?
+                .to_string()
+                .trim_end()
+                .to_owned()),
+            day => Err(format!("Nothing to show for day {}, try `solve`", day)),
+        }
+    }
+
+    /// Runs one line of the session and returns what to print.
+    pub fn execute(&mut self, line: &str) -> Result<String, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let (command, args) = match words.split_first() {
+            Some((command, args)) => (*command, args),
+            None => return Ok(String::new()),
+        };
+        match command {
+            "help" => Ok(HELP.to_owned()),
+            "load" => self.load(args),
+            "solve" => self.solve(args),
+            "show" => self.show(),
+            "measure" => {
+                let depths = self.parse::<day01::Day01>(1, command)?;
+                match argument(args, "window size")? {
+                    window if (1..=depths.len()).contains(&window) => {
+                        Ok(day01::measure(&depths, window).to_string())
+                    }
+                    _ => Err(format!("The window should be from 1 to {}", depths.len())),
+                }
+            }
+            "fish" => {
+                let timers = self.parse::<day06::Day06>(6, command)?;
+                Ok(day06::count_laternfish(&timers, argument(args, "number of days")?).to_string())
+            }
+            "flashes" => {
+                let grid = self.parse::<day11::Day11>(11, command)?;
+                Ok(day11::calc_flashes(&grid, argument(args, "number of steps")?).to_string())
+            }
+            "fold" => {
+                let paper = self.parse::<day13::Day13>(13, command)?;
+                let folded = paper.folded(argument(args, "number of folds")?);
+                Ok(format!(
+                    "{} dots\n{}",
+                    folded.dot_count(),
+                    folded.to_string().trim_end()
+                ))
+            }
+            "polymer" => {
+                let polymer = self.parse::<day14::Day14>(14, command)?;
+                let steps = argument(args, "number of steps")?;
+                Ok(day14::count_quantity_diff_from_generated_polymer(&polymer, steps).to_string())
+            }
+            "tile" => {
+                let grid = self.parse::<day15::Day15>(15, command)?;
+                match argument(args, "factor")? {
+                    0 => Err("The factor should be at least 1".to_owned()),
+                    factor => {
+                        Ok(day15::find_lowest_risk_path(&day15::tile(&grid, factor)).to_string())
+                    }
+                }
+            }
+            _ => Err(format!("Unknown command `{}`, try `help`", command)),
+        }
+    }
+}
+
+pub fn repl(day: Option<u8>) -> bool {
+    let mut session = Session::default();
+    if let Some(day) = day {
+        match session.execute(&format!("load {}", day)) {
+            Ok(loaded) => println!("{}", loaded),
+            Err(why) => eprintln!("{}", why),
+        }
+    }
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        let prompt = match &session.loaded {
+            Some(loaded) => format!("day{:02}> ", loaded.day),
+            None => "aoc> ".to_owned(),
+        };
+        print!("{}", prompt);
+        io::stdout().flush().expect("stdout is writable");
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            Some(Err(why)) => {
+                eprintln!("{}", why);
+                return false;
+            }
+            None => return true,
+        };
+        if matches!(line.trim(), "quit" | "exit") {
+            return true;
+        }
+        match session.execute(&line) {
+            Ok(output) if output.is_empty() => {}
+            Ok(output) => println!("{}", output),
+            Err(why) => eprintln!("{}", why),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::{parse_repl_args, Session};
+
+    #[test]
+    fn test_parse_repl_args() {
+        assert_eq!(Ok(None), parse_repl_args(&[]));
+        assert_eq!(Ok(Some(13)), parse_repl_args(&["13".to_owned()]));
+        assert!(parse_repl_args(&["30".to_owned()]).is_err());
+    }
+
+    #[test]
+    fn test_execute() {
+        let path = env::temp_dir().join("aoc-repl-day06.txt");
+        fs::write(&path, "3,4,3,1,2\n").unwrap();
+        let mut session = Session::default();
+        assert!(session
+            .execute("fish 18")
+            .unwrap_err()
+            .contains("No input loaded"));
+        assert!(session
+            .execute(&format!("load 6 {}", path.display()))
+            .is_ok());
+        assert_eq!("26", session.execute("fish 18").unwrap());
+        assert_eq!("Part 1: 5934", session.execute("solve 1").unwrap());
+        assert!(session
+            .execute("fish many")
+            .unwrap_err()
+            .contains("Invalid number of days"));
+        assert!(session
+            .execute("measure 3")
+            .unwrap_err()
+            .contains("day 1 command"));
+        assert!(session.execute("show").is_err());
+        assert!(session
+            .execute("dance")
+            .unwrap_err()
+            .contains("Unknown command"));
+        assert_eq!("", session.execute("   ").unwrap());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_show() {
+        let path = env::temp_dir().join("aoc-repl-day16.txt");
+        fs::write(&path, "38006F45291200\n").unwrap();
+        let mut session = Session::default();
+        session
+            .execute(&format!("load 16 {}", path.display()))
+            .unwrap();
+        assert_eq!(
+            "v1 less than = 1\n  v6 literal 10\n  v2 literal 20",
+            session.execute("show").unwrap()
+        );
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,236 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub const USAGE: &str = "Usage: aoc new-day <DAY>
+
+Creates `src/days/dayNN.rs` with a solver skeleton and example tests, registers it in
+`src/days/mod.rs`, and adds empty `data/dayNN.txt` and `data/dayNN-small.txt` inputs.";
+
+const TEMPLATE: &str = r#"use crate::{Answer, Error, ParseResult, Solution};
+
+pub struct DayNN;
+
+fn parse_input(input: &str) -> ParseResult<Vec<String>> {
+    input
+        .lines()
+        .map(|line| line.trim())
+        .map(|line| match line.is_empty() {
+            true => Err(Error::parse(input, line, "unexpected empty line")),
+            false => Ok(line.to_owned()),
+        })
+        .collect()
+}
+
+impl Solution for DayNN {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> ParseResult<Vec<String>> {
+        parse_input(input)
+    }
+
+    fn part1(lines: &Vec<String>) -> Answer {
+        lines.len().into()
+    }
+
+    fn part2(lines: &Vec<String>) -> Answer {
+        lines.len().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{read_file_to_string, Answer, Solution};
+
+    use super::{parse_input, DayNN};
+
+    #[test]
+    #[ignore = "fill in data/dayNN-small.txt and the expected answer"]
+    fn test_part1() {
+        let lines = parse_input(&read_file_to_string("dayNN-small").unwrap()).unwrap();
+        assert_eq!(Answer::Number(0), DayNN::part1(&lines));
+    }
+
+    #[test]
+    #[ignore = "fill in data/dayNN-small.txt and the expected answer"]
+    fn test_part2() {
+        let lines = parse_input(&read_file_to_string("dayNN-small").unwrap()).unwrap();
+        assert_eq!(Answer::Number(0), DayNN::part2(&lines));
+    }
+}
+"#;
+
+pub fn parse_new_day_args(args: &[String]) -> Result<u8, String> {
+    match args {
+        [day] => match day.parse::<u8>() {
+            Ok(number) if (1..=25).contains(&number) => Ok(number),
+            _ => Err(format!("Advent of Code has no day `{}`", day)),
+        },
+        _ => Err("new-day expects exactly one day".to_owned()),
+    }
+}
+
+/// Adds `pub mod dayNN;` and its `day!` entry to the source of `src/days/mod.rs`, keeping both
+/// lists sorted.
+fn register(registry: &str, number: u8) -> Result<String, String> {
+    let module = format!("day{:02}", number);
+    let declaration = format!("pub mod {};", module);
+    let entry = format!("    day!({}, {}::Day{:02}),", number, module, number);
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+    if lines.contains(&declaration) {
+        return Err(format!("{} is already registered", module));
+    }
+
+    let modules: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with("pub mod day"))
+        .collect();
+    let last_module = *modules.last().ok_or("Can't find the day modules")?;
+    let position = modules.iter().find(|&&i| lines[i] > declaration).copied();
+    lines.insert(position.unwrap_or(last_module + 1), declaration);
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const DAYS: [Day; "))
+        .ok_or("Can't find the DAYS table")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "];")
+            .ok_or("Can't find the end of DAYS")?;
+    let mut entries: Vec<String> = lines.drain(start + 1..end).collect();
+    entries.push(entry);
+    let day_of = |entry: &String| {
+        entry
+            .trim_start_matches(|c: char| !c.is_ascii_digit())
+            .split(',')
+            .next()
+            .and_then(|number| number.parse::<u8>().ok())
+    };
+    entries.sort_by_key(day_of);
+    lines[start] = format!("pub const DAYS: [Day; {}] = [", entries.len());
+    lines.splice(start + 1..start + 1, entries);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Generates and registers day `number` under `root`, returning the files it created.
+pub fn scaffold(root: &Path, number: u8) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day{:02}", number);
+    let source = root.join("src/days").join(format!("{}.rs", name));
+    if source.exists() {
+        return Err(format!("{} already exists", source.display()));
+    }
+    let registry_path = root.join("src/days/mod.rs");
+    let registry = fs::read_to_string(&registry_path)
+        .map_err(|why| format!("Can't read {}: {}", registry_path.display(), why))?;
+    let registry = register(&registry, number)?;
+
+    let write = |path: &Path, content: &str| {
+        fs::write(path, content).map_err(|why| format!("Can't write {}: {}", path.display(), why))
+    };
+    let mut created = vec![];
+    write(
+        &source,
+        &TEMPLATE
+            .replace("DayNN", &format!("Day{:02}", number))
+            .replace("dayNN", &name),
+    )?;
+    created.push(source);
+    write(&registry_path, &registry)?;
+    for data in [format!("{}.txt", name), format!("{}-small.txt", name)] {
+        let path = root.join("data").join(data);
+        if !path.exists() {
+            write(&path, "")?;
+            created.push(path);
+        }
+    }
+    Ok(created)
+}
+
+pub fn new_day(number: u8) -> bool {
+    match scaffold(Path::new(env!("CARGO_MANIFEST_DIR")), number) {
+        Ok(created) => {
+            for path in created {
+                println!("Created {}", path.display());
+            }
+            println!("Registered day{:02} in src/days/mod.rs", number);
+            true
+        }
+        Err(why) => {
+            eprintln!("{}", why);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::{parse_new_day_args, register, scaffold};
+
+    const REGISTRY: &str = "pub mod day01;
+pub mod day03;
+
+pub const DAYS: [Day; 2] = [
+    day!(1, day01::Day01),
+    day!(3, day03::Day03),
+];
+";
+
+    #[test]
+    fn test_parse_new_day_args() {
+        assert_eq!(Ok(17), parse_new_day_args(&["17".to_owned()]));
+        assert!(parse_new_day_args(&["26".to_owned()]).is_err());
+        assert!(parse_new_day_args(&[]).is_err());
+    }
+
+    #[test]
+    fn test_register() {
+        assert_eq!(
+            "pub mod day01;
+pub mod day02;
+pub mod day03;
+
+pub const DAYS: [Day; 3] = [
+    day!(1, day01::Day01),
+    day!(2, day02::Day02),
+    day!(3, day03::Day03),
+];
+",
+            register(REGISTRY, 2).unwrap()
+        );
+        let registry = register(REGISTRY, 17).unwrap();
+        assert!(registry.contains("pub mod day03;\npub mod day17;\n"));
+        assert!(registry.contains("    day!(17, day17::Day17),\n];"));
+        assert!(register(REGISTRY, 3).is_err());
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = env::temp_dir().join("aoc-scaffold-test");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::create_dir_all(root.join("data")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), REGISTRY).unwrap();
+        fs::write(root.join("data/day17.txt"), "real input").unwrap();
+
+        let created = scaffold(&root, 17).unwrap();
+        assert_eq!(
+            vec![
+                root.join("src/days/day17.rs"),
+                root.join("data/day17-small.txt")
+            ],
+            created
+        );
+        let source = fs::read_to_string(root.join("src/days/day17.rs")).unwrap();
+        assert!(source.contains("impl Solution for Day17"));
+        assert!(source.contains("read_file_to_string(\"day17-small\")"));
+        assert_eq!(
+            "real input",
+            fs::read_to_string(root.join("data/day17.txt")).unwrap()
+        );
+        assert!(scaffold(&root, 17).is_err());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
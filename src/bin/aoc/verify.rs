@@ -0,0 +1,109 @@
+use std::io;
+
+use advent_of_code::{answers::Verdict, days, Answers, Error, InputSource};
+
+use crate::parse_days;
+
+pub const USAGE: &str = "Usage: aoc verify [DAYS] [--record]
+
+Runs every selected day on its real input and compares the answers with
+`$AOC_DATA_DIR/answers.toml`. Days whose input is missing are skipped.
+--record stores the current answers instead of only reporting them.";
+
+#[derive(Debug, PartialEq)]
+pub struct VerifyOptions {
+    pub days: Vec<u8>,
+    pub record: bool,
+}
+
+pub fn parse_verify_args(args: &[String]) -> Result<VerifyOptions, String> {
+    let mut options = VerifyOptions {
+        days: parse_days("all")?,
+        record: false,
+    };
+    for arg in args {
+        match arg.as_str() {
+            "--record" => options.record = true,
+            flag if flag.starts_with("--") => return Err(format!("Unknown option `{}`", flag)),
+            days => options.days = parse_days(days)?,
+        }
+    }
+    Ok(options)
+}
+
+/// Returns false when an answer doesn't match the recorded one or a day fails to run.
+pub fn verify(options: &VerifyOptions) -> bool {
+    let path = Answers::path();
+    let mut answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(why) => {
+            eprintln!("{}", why);
+            return false;
+        }
+    };
+    let mut succeeded = true;
+    for number in &options.days {
+        let day = days::find(*number).expect("days are validated while parsing arguments");
+        let source = InputSource::resolve(None, &day.name());
+        let results = match source.read().and_then(|input| {
+            day.solve(&input, &[1, 2])
+                .map_err(|why| why.in_file(&source.to_string()))
+        }) {
+            Ok(results) => results,
+            Err(Error::Io { source: why, .. }) if why.kind() == io::ErrorKind::NotFound => {
+                println!("Day {:02}: skipped, no input at {}", day.number, source);
+                continue;
+            }
+            Err(why) => {
+                eprintln!("Day {:02}: {}", day.number, why);
+                succeeded = false;
+                continue;
+            }
+        };
+        for (part, answer) in (1..=2).zip(results) {
+            match answers.verify(day.number, part, &answer) {
+                Verdict::Correct => println!("Day {:02} part {}: ok", day.number, part),
+                Verdict::Mismatch { expected } => {
+                    println!(
+                        "Day {:02} part {}: MISMATCH, expected {}, got {}",
+                        day.number, part, expected, answer
+                    );
+                    succeeded = false;
+                }
+                Verdict::Unrecorded => {
+                    println!("Day {:02} part {}: no recorded answer", day.number, part)
+                }
+            }
+            if options.record {
+                answers.insert(day.number, part, answer);
+            }
+        }
+    }
+    if options.record {
+        if let Err(why) = answers.save(&path) {
+            eprintln!("{}", why);
+            return false;
+        }
+        println!("Recorded answers in {}", path.display());
+    }
+    succeeded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_verify_args, VerifyOptions};
+
+    #[test]
+    fn test_parse_verify_args() {
+        let args: Vec<String> = vec!["3-4".to_owned(), "--record".to_owned()];
+        assert_eq!(
+            VerifyOptions {
+                days: vec![3, 4],
+                record: true
+            },
+            parse_verify_args(&args).unwrap()
+        );
+        assert!(!parse_verify_args(&[]).unwrap().record);
+        assert!(parse_verify_args(&["--part".to_owned()]).is_err());
+    }
+}
@@ -0,0 +1,191 @@
+//! A grid of booleans packed in bits, for sets of positions.
+
+use std::ops::{BitAndAssign, BitOrAssign};
+
+use bitvec::prelude::*;
+
+/// A set of positions of a `height` by `width` grid, indexed with `(row, column)` like [`crate::Grid`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    height: usize,
+    width: usize,
+    bits: BitVec<Lsb0, usize>,
+}
+
+impl BitGrid {
+    /// A grid with no bit set.
+    pub fn new(height: usize, width: usize) -> BitGrid {
+        BitGrid {
+            height,
+            width,
+            bits: BitVec::repeat(false, height * width),
+        }
+    }
+
+    pub fn from_fn(
+        height: usize,
+        width: usize,
+        mut f: impl FnMut((usize, usize)) -> bool,
+    ) -> BitGrid {
+        let mut grid = BitGrid::new(height, width);
+        for index in 0..height * width {
+            grid.bits.set(index, f((index / width, index % width)));
+        }
+        grid
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    fn index(&self, (row, column): (usize, usize)) -> usize {
+        assert!(
+            row < self.height && column < self.width,
+            "({}, {}) is out of the {}x{} grid",
+            row,
+            column,
+            self.height,
+            self.width
+        );
+        row * self.width + column
+    }
+
+    /// Sets the bit at `position`, returning whether it wasn't yet.
+    pub fn set(&mut self, position: (usize, usize)) -> bool {
+        let index = self.index(position);
+        let was_set = self.bits[index];
+        self.bits.set(index, true);
+        !was_set
+    }
+
+    /// Whether the bit at `position` is set, which it never is off the grid.
+    pub fn test(&self, (row, column): (usize, usize)) -> bool {
+        row < self.height && column < self.width && self.bits[row * self.width + column]
+    }
+
+    /// Clears the bit at `position`, returning whether it was set.
+    pub fn clear(&mut self, position: (usize, usize)) -> bool {
+        let index = self.index(position);
+        let was_set = self.bits[index];
+        self.bits.set(index, false);
+        was_set
+    }
+
+    /// How many bits are set.
+    pub fn count(&self) -> usize {
+        self.bits.count_ones()
+    }
+
+    /// The positions of the bits set, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let width = self.width;
+        self.bits
+            .iter_ones()
+            .map(move |index| (index / width, index % width))
+    }
+
+    /// The top left `height` by `width` corner, which may be bigger than the grid.
+    pub fn crop(&self, height: usize, width: usize) -> BitGrid {
+        BitGrid::from_fn(height, width, |position| self.test(position))
+    }
+
+    /// Draws the grid with `set` and `unset` characters, one row per line.
+    pub fn render(&self, set: char, unset: char) -> String {
+        let mut picture = String::with_capacity((self.width + 1) * self.height);
+        for row in 0..self.height {
+            let bits = &self.bits[row * self.width..(row + 1) * self.width];
+            picture.extend(bits.iter().map(|bit| if *bit { set } else { unset }));
+            picture.push('\n');
+        }
+        picture
+    }
+
+    fn assert_same_size(&self, other: &BitGrid) {
+        assert_eq!(
+            (self.height, self.width),
+            (other.height, other.width),
+            "grids should have the same size"
+        );
+    }
+}
+
+/// Union, of grids of the same size.
+impl BitOrAssign<&BitGrid> for BitGrid {
+    fn bitor_assign(&mut self, other: &BitGrid) {
+        self.assert_same_size(other);
+        for (word, other) in self
+            .bits
+            .as_mut_raw_slice()
+            .iter_mut()
+            .zip(other.bits.as_raw_slice())
+        {
+            *word |= other;
+        }
+    }
+}
+
+/// Intersection, of grids of the same size.
+impl BitAndAssign<&BitGrid> for BitGrid {
+    fn bitand_assign(&mut self, other: &BitGrid) {
+        self.assert_same_size(other);
+        for (word, other) in self
+            .bits
+            .as_mut_raw_slice()
+            .iter_mut()
+            .zip(other.bits.as_raw_slice())
+        {
+            *word &= other;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitGrid;
+
+    #[test]
+    fn test_set_test_clear() {
+        let mut grid = BitGrid::new(3, 70);
+        assert!(grid.set((0, 1)));
+        assert!(!grid.set((0, 1)));
+        assert!(grid.set((2, 69)));
+        assert!(grid.test((2, 69)));
+        assert!(!grid.test((1, 69)));
+        assert!(!grid.test((3, 0)));
+        assert_eq!(2, grid.count());
+        assert_eq!(vec![(0, 1), (2, 69)], grid.iter().collect::<Vec<_>>());
+        assert!(grid.clear((0, 1)));
+        assert!(!grid.clear((0, 1)));
+        assert_eq!(1, grid.count());
+    }
+
+    #[test]
+    #[should_panic(expected = "(3, 0) is out of the 3x70 grid")]
+    fn test_set_out_of_grid() {
+        BitGrid::new(3, 70).set((3, 0));
+    }
+
+    #[test]
+    fn test_union_and_intersection() {
+        let diagonal = BitGrid::from_fn(9, 9, |(row, column)| row == column);
+        let top = BitGrid::from_fn(9, 9, |(row, _)| row == 0);
+        let mut union = diagonal.clone();
+        union |= &top;
+        assert_eq!(17, union.count());
+        let mut intersection = diagonal;
+        intersection &= &top;
+        assert_eq!(vec![(0, 0)], intersection.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_render_and_crop() {
+        let grid = BitGrid::from_fn(3, 4, |(row, column)| (row + column) % 2 == 0);
+        assert_eq!("#.#.\n.#.#\n#.#.\n", grid.render('#', '.'));
+        assert_eq!("#.#\n.#.\n", grid.crop(2, 3).render('#', '.'));
+        assert_eq!("#.#..\n", grid.crop(1, 5).render('#', '.'));
+    }
+}
@@ -0,0 +1,56 @@
+//! Helpers on the standard collections.
+
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{BuildHasher, Hash},
+    mem,
+};
+
+/// Removes the entries matching a predicate and returns them, keeping the others in place.
+///
+/// Works on every toolchain, unlike the unstable `drain_filter`, and unlike the lazy `extract_if`
+/// the removal doesn't depend on consuming the result.
+pub trait DrainWhere<T> {
+    fn drain_where(&mut self, predicate: impl FnMut(&T) -> bool) -> Self;
+}
+
+impl<T: Eq + Hash, S: BuildHasher + Default> DrainWhere<T> for HashSet<T, S> {
+    fn drain_where(&mut self, mut predicate: impl FnMut(&T) -> bool) -> Self {
+        let (drained, kept) = mem::take(self)
+            .into_iter()
+            .partition(|item| predicate(item));
+        *self = kept;
+        drained
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Default> DrainWhere<(K, V)> for HashMap<K, V, S> {
+    fn drain_where(&mut self, mut predicate: impl FnMut(&(K, V)) -> bool) -> Self {
+        let (drained, kept) = mem::take(self)
+            .into_iter()
+            .partition(|entry| predicate(entry));
+        *self = kept;
+        drained
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use super::DrainWhere;
+
+    #[test]
+    fn test_drain_where() {
+        let mut numbers: HashSet<u32> = (1..=10).collect();
+        let even = numbers.drain_where(|n| n % 2 == 0);
+        assert_eq!(HashSet::from([2, 4, 6, 8, 10]), even);
+        assert_eq!(HashSet::from([1, 3, 5, 7, 9]), numbers);
+        assert!(numbers.drain_where(|_| false).is_empty());
+
+        let mut ages = HashMap::from([("ann", 31), ("bob", 12), ("cid", 17)]);
+        let minors = ages.drain_where(|(_, age)| *age < 18);
+        assert_eq!(HashMap::from([("bob", 12), ("cid", 17)]), minors);
+        assert_eq!(HashMap::from([("ann", 31)]), ages);
+    }
+}
@@ -0,0 +1,205 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A point or a vector on the plane. Coordinates order by `x`, then by `y`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coordinate<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Coordinate<T> {
+    pub fn new(x: T, y: T) -> Coordinate<T> {
+        Coordinate { x, y }
+    }
+}
+
+fn abs_diff<T: Copy + Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    match a > b {
+        true => a - b,
+        false => b - a,
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Coordinate<T> {
+    /// Distance when moving only horizontally and vertically.
+    pub fn manhattan(self, other: Coordinate<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Distance when diagonal moves are allowed too, like a king on a chessboard.
+    pub fn chebyshev(self, other: Coordinate<T>) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+}
+
+impl<T: Neg<Output = T>> Coordinate<T> {
+    /// Quarter turn counter-clockwise around the origin, with `y` pointing up.
+    pub fn rotate_left(self) -> Coordinate<T> {
+        Coordinate::new(-self.y, self.x)
+    }
+
+    /// Quarter turn clockwise around the origin, with `y` pointing up.
+    pub fn rotate_right(self) -> Coordinate<T> {
+        Coordinate::new(self.y, -self.x)
+    }
+}
+
+impl<T: Copy + Neg<Output = T> + Add<Output = T> + Sub<Output = T>> Coordinate<T> {
+    /// Turns `quarter_turns` times counter-clockwise around `center`; negative turns go clockwise.
+    pub fn rotate_around(self, center: Coordinate<T>, quarter_turns: i32) -> Coordinate<T> {
+        let offset = self - center;
+        let offset = match quarter_turns.rem_euclid(4) {
+            0 => offset,
+            1 => offset.rotate_left(),
+            2 => -offset,
+            _ => offset.rotate_right(),
+        };
+        center + offset
+    }
+}
+
+impl<T: Add<Output = T>> Add for Coordinate<T> {
+    type Output = Coordinate<T>;
+
+    fn add(self, other: Coordinate<T>) -> Coordinate<T> {
+        Coordinate::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Coordinate<T> {
+    type Output = Coordinate<T>;
+
+    fn sub(self, other: Coordinate<T>) -> Coordinate<T> {
+        Coordinate::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Coordinate<T> {
+    type Output = Coordinate<T>;
+
+    fn mul(self, factor: T) -> Coordinate<T> {
+        Coordinate::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Coordinate<T> {
+    type Output = Coordinate<T>;
+
+    fn neg(self) -> Coordinate<T> {
+        Coordinate::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Coordinate<T> {
+    fn add_assign(&mut self, other: Coordinate<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Coordinate<T> {
+    fn sub_assign(&mut self, other: Coordinate<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T> From<(T, T)> for Coordinate<T> {
+    fn from((x, y): (T, T)) -> Coordinate<T> {
+        Coordinate::new(x, y)
+    }
+}
+
+impl<T> From<Coordinate<T>> for (T, T) {
+    fn from(coordinate: Coordinate<T>) -> (T, T) {
+        (coordinate.x, coordinate.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Coordinate<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::Coordinate;
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (Coordinate::new(3, -2), Coordinate::new(1, 5));
+        assert_eq!(Coordinate::new(4, 3), a + b);
+        assert_eq!(Coordinate::new(2, -7), a - b);
+        assert_eq!(Coordinate::new(9, -6), a * 3);
+        assert_eq!(Coordinate::new(-3, 2), -a);
+        let mut c = a;
+        c += b;
+        c -= Coordinate::new(0, 3);
+        assert_eq!(Coordinate::new(4, 0), c);
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Coordinate::new(1, 1), Coordinate::new(4, 6));
+        assert_eq!(8, a.manhattan(b));
+        assert_eq!(5, a.chebyshev(b));
+        assert_eq!(
+            8u32,
+            Coordinate::new(4u32, 6).manhattan(Coordinate::new(1, 1))
+        );
+    }
+
+    #[test]
+    fn test_rotation() {
+        let east = Coordinate::new(1, 0);
+        assert_eq!(Coordinate::new(0, 1), east.rotate_left());
+        assert_eq!(Coordinate::new(0, -1), east.rotate_right());
+        assert_eq!(
+            east,
+            east.rotate_left().rotate_left().rotate_left().rotate_left()
+        );
+        let center = Coordinate::new(2, 2);
+        assert_eq!(
+            Coordinate::new(2, 4),
+            Coordinate::new(4, 2).rotate_around(center, 1)
+        );
+        assert_eq!(
+            Coordinate::new(0, 2),
+            Coordinate::new(4, 2).rotate_around(center, 2)
+        );
+        assert_eq!(
+            Coordinate::new(2, 0),
+            Coordinate::new(4, 2).rotate_around(center, -1)
+        );
+    }
+
+    #[test]
+    fn test_ordering_and_hashing() {
+        let mut points = vec![
+            Coordinate::new(2, 1),
+            Coordinate::new(1, 5),
+            Coordinate::new(1, 2),
+        ];
+        points.sort();
+        assert_eq!(
+            vec![
+                Coordinate::new(1, 2),
+                Coordinate::new(1, 5),
+                Coordinate::new(2, 1)
+            ],
+            points
+        );
+        let unique: HashSet<_> = [(1, 2), (1, 2), (2, 1)]
+            .into_iter()
+            .map(Coordinate::from)
+            .collect();
+        assert_eq!(2, unique.len());
+        assert_eq!("1,2", Coordinate::new(1, 2).to_string());
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::VecDeque;
+
+use crate::{
+    parse,
+    stream::{Lines, StreamingSolution},
+    Answer, Error, ParseResult, Solution,
+};
+
+pub struct Day01;
+
+fn get_depth(input: &str, depth: &str) -> ParseResult<i32> {
+    depth.parse().map_err(|_| Error::parse(input, depth, "expected a depth"))
+}
+
+fn get_depths(input: &str) -> ParseResult<Vec<i32>> {
+    parse::each_line(input, |line| get_depth(input, line))
+}
+
+fn window_size(part: u8) -> ParseResult<usize> {
+    match part {
+        1 => Ok(1),
+        2 => Ok(3),
+        part => Err(Error::UnknownPart { part }),
+    }
+}
+
+pub fn measure(numbers: &[i32], window_size: usize) -> i32 {
+    if numbers.len() < window_size {
+        return 0;
+    }
+    let mut counter = 0;
+    let mut window_sum: i32 = numbers[..window_size].iter().sum();
+    for idx in window_size..numbers.len() {
+        let pre_window_sum = window_sum;
+        window_sum += numbers[idx] - numbers[idx - window_size];
+        if window_sum > pre_window_sum {
+            counter += 1;
+        }
+    }
+    counter
+}
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> ParseResult<Vec<i32>> {
+        get_depths(input)
+    }
+
+    fn part1(numbers: &Vec<i32>) -> Answer {
+        measure(numbers, 1).into()
+    }
+
+    fn part2(numbers: &Vec<i32>) -> Answer {
+        measure(numbers, 3).into()
+    }
+}
+
+/// Comparing sliding sums only needs the depths leaving the windows, so this keeps the last
+/// three depths.
+impl StreamingSolution for Day01 {
+    fn solve_stream(lines: Lines, parts: &[u8]) -> ParseResult<Vec<Answer>> {
+        let windows: Vec<usize> = parts.iter().map(|&part| window_size(part)).collect::<ParseResult<_>>()?;
+        let mut counts = vec![0; windows.len()];
+        let mut last: VecDeque<i32> = VecDeque::with_capacity(4);
+        for depth in lines.records(|line| get_depth(line, line)) {
+            let depth = depth?;
+            for (count, &window) in counts.iter_mut().zip(&windows) {
+                if last.len() >= window && depth > last[last.len() - window] {
+                    *count += 1;
+                }
+            }
+            if last.len() == 3 {
+                last.pop_front();
+            }
+            last.push_back(depth);
+        }
+        Ok(counts.into_iter().map(Answer::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use proptest::prelude::*;
+
+    use crate::{
+        stream::{Lines, StreamingSolution},
+        Answer,
+    };
+
+    use super::{get_depths, measure, Day01};
+
+    #[test]
+    fn test_measure() {
+        assert_eq!(
+            7,
+            measure(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263], 1)
+        );
+        assert_eq!(
+            5,
+            measure(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263], 3)
+        );
+        assert_eq!(0, measure(&[199, 200], 3));
+        assert_eq!(0, measure(&[], 1));
+    }
+
+    #[test]
+    fn test_get_depths() {
+        assert_eq!(vec![199, 200], get_depths("  199\n  200\n\n").unwrap());
+        assert_eq!("2:3: expected a depth, found `x`", get_depths("1\n  x\n").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_solve_stream() {
+        let lines = Lines::new(Cursor::new("199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n"), "memory");
+        assert_eq!(vec![Answer::Number(7), Answer::Number(5)], Day01::solve_stream(lines, &[1, 2]).unwrap());
+        let lines = Lines::new(Cursor::new("199\n200\n"), "memory");
+        assert_eq!("There is no part 3", Day01::solve_stream(lines, &[3]).unwrap_err().to_string());
+        let lines = Lines::new(Cursor::new("199\n200\ndeep\n"), "memory");
+        assert_eq!(
+            "3:1: expected a depth, found `deep`",
+            Day01::solve_stream(lines, &[1]).unwrap_err().to_string()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_solve_stream_matches_measure(depths in prop::collection::vec(0..10_000, 0..200)) {
+            let text: String = depths.iter().map(|depth| format!("{}\n", depth)).collect();
+            let answers = Day01::solve_stream(Lines::new(Cursor::new(text), "memory"), &[1, 2]).unwrap();
+            let expected: Vec<Answer> = vec![measure(&depths, 1).into(), measure(&depths, 3).into()];
+            prop_assert_eq!(expected, answers);
+        }
+
+        #[test]
+        fn prop_measure_counts_increases(depths in prop::collection::vec(0..10_000, 1..200)) {
+            let increases = depths.windows(2).filter(|pair| pair[1] > pair[0]).count();
+            prop_assert_eq!(increases as i32, measure(&depths, 1));
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{parse, Answer, Error, ParseResult, Solution};
+
+pub struct Day02;
+
+pub enum Operation {
+    Forward(i32),
+    Up(i32),
+    Down(i32),
+}
+
+struct Position {
+    x: i32,
+    z: i32,
+}
+
+impl Position {
+    fn new(x: i32, z: i32) -> Position {
+        Position { x, z }
+    }
+}
+
+fn get_operation(input: &str, line: &str) -> ParseResult<Operation> {
+    let mut split = line.split_ascii_whitespace();
+    let direction = split.next().ok_or_else(|| Error::parse(input, line, "expected an operation"))?;
+    let unit_str = split.next().ok_or_else(|| Error::parse(input, line, "expected a unit"))?;
+    let unit = unit_str.parse::<i32>().map_err(|_| Error::parse(input, unit_str, "expected a unit"))?;
+    Ok(match direction {
+        "forward" => Operation::Forward(unit),
+        "up" => Operation::Up(unit),
+        "down" => Operation::Down(unit),
+        _ => return Err(Error::parse(input, direction, "Can't translate to operation")),
+    })
+}
+
+fn summed_moves(operations: &[Operation]) -> Position {
+    let mut x = 0;
+    let mut z = 0;
+    for op in operations {
+        match op {
+            Operation::Forward(unit) => x += unit,
+            Operation::Down(unit) => z += unit,
+            Operation::Up(unit) => z -= unit,
+        }
+    }
+
+    Position::new(x, z)
+}
+
+fn aimed_moves(operations: &[Operation]) -> Position {
+    let mut x = 0;
+    let mut z = 0;
+    let mut aim = 0;
+
+    for op in operations {
+        match op {
+            Operation::Forward(unit) => {
+                x += unit;
+                z += unit * aim
+            }
+            Operation::Down(unit) => aim += unit,
+            Operation::Up(unit) => aim -= unit,
+        }
+    }
+
+    Position::new(x, z)
+}
+
+fn get_operations(input: &str) -> ParseResult<Vec<Operation>> {
+    parse::each_line(input, |line| get_operation(input, line))
+}
+
+impl Solution for Day02 {
+    type Input = Vec<Operation>;
+
+    fn parse(input: &str) -> ParseResult<Vec<Operation>> {
+        get_operations(input)
+    }
+
+    fn part1(operations: &Vec<Operation>) -> Answer {
+        let summed_position = summed_moves(operations);
+        (summed_position.x * summed_position.z).into()
+    }
+
+    fn part2(operations: &Vec<Operation>) -> Answer {
+        let aimed_position = aimed_moves(operations);
+        (aimed_position.x * aimed_position.z).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{aimed_moves, get_operations, summed_moves};
+
+    #[test]
+    fn test_summed_moves() {
+        let data = "forward 5
+                    down 5
+                    forward 8
+                    up 3
+                    down 8
+                    forward 2";
+        let operations = get_operations(data).unwrap();
+        let position = summed_moves(&operations);
+        assert_eq!(15, position.x);
+        assert_eq!(10, position.z);
+    }
+
+    #[test]
+    fn test_aimed_moves() {
+        let data = "forward 5
+                    down 5
+                    forward 8
+                    up 3
+                    down 8
+                    forward 2";
+        let operations = get_operations(data).unwrap();
+        let position = aimed_moves(&operations);
+        assert_eq!(15, position.x);
+        assert_eq!(60, position.z);
+    }
+
+    #[test]
+    fn test_get_operations_error() {
+        let error = get_operations("forward 5\nbackward 3").err().unwrap();
+        assert_eq!(
+            "2:1: Can't translate to operation, found `backward`",
+            error.to_string()
+        );
+        assert!(get_operations("up five").is_err());
+        assert!(get_operations("down").is_err());
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{parse, Answer, Error, ParseResult, Solution};
+
+pub struct Day03;
+
+fn count_bits(bytes_numbers: &[Vec<u8>], index: usize) -> i32 {
+    let mut counter = 0;
+    for bytes in bytes_numbers {
+        counter += match bytes[index] {
+            b'1' => 1,
+            b'0' => -1,
+            _ => 0,
+        };
+    }
+    counter
+}
+
+fn to_bytes_numbers(input: &str) -> ParseResult<Vec<Vec<u8>>> {
+    let lines: Vec<&str> = parse::lines(input).collect();
+    let bit_length = lines.first().map_or(0, |line| line.len());
+    if bit_length == 0 {
+        return Err(Error::parse(input, input, "expected binary numbers"));
+    }
+    let bytes_numbers: Vec<Vec<u8>> = lines
+        .iter()
+        .map(|line| {
+            if let Some((i, c)) = line.char_indices().find(|&(_, c)| c != '0' && c != '1') {
+                return Err(Error::parse(input, &line[i..i + c.len_utf8()], "expected a binary digit"));
+            }
+            if line.len() != bit_length {
+                return Err(Error::parse(input, line, format!("expected {} bits", bit_length)));
+            }
+            Ok(line.as_bytes().to_vec())
+        })
+        .collect::<ParseResult<_>>()?;
+    // The power consumption needs a most common bit at every position.
+    if let Some(index) = (0..bit_length).find(|&index| count_bits(&bytes_numbers, index) == 0) {
+        return Err(Error::parse(input, &lines[0][index..index + 1], "expected a most common bit"));
+    }
+    Ok(bytes_numbers)
+}
+
+pub fn diagnose_power_consumption(bytes_numbers: &[Vec<u8>]) -> (i32, i32) {
+    let bit_length = bytes_numbers[0].len();
+    let mut gamma = 0;
+    let mut epsilon = 0;
+    for i in 0..bit_length {
+        gamma <<= 1;
+        epsilon <<= 1;
+        match count_bits(bytes_numbers, i) {
+            count if count > 0 => gamma += 1,
+            count if count < 0 => epsilon += 1,
+            _ => unreachable!("bits are checked while parsing"),
+        }
+    }
+    (gamma, epsilon)
+}
+
+fn get_most_common_value(bytes_numbers: &[Vec<u8>], index: usize) -> u8 {
+    match count_bits(bytes_numbers, index) {
+        count if count > 0 => b'1',
+        count if count < 0 => b'0',
+        _ => b'1',
+    }
+}
+
+fn get_least_common_value(bytes_numbers: &[Vec<u8>], index: usize) -> u8 {
+    match count_bits(bytes_numbers, index) {
+        count if count > 0 => b'0',
+        count if count < 0 => b'1',
+        _ => b'0',
+    }
+}
+
+type CriteriaFn = dyn Fn(&[Vec<u8>], usize) -> u8;
+
+/// Keeps the numbers matching `criteria` bit after bit, until one is left. When they all have
+/// the same bit, which only duplicates can leave, they are all kept.
+fn filter_by_criteria(bytes_numbers: &[Vec<u8>], bit_length: usize, criteria: &CriteriaFn) -> Vec<u8> {
+    let mut bytes_numbers = bytes_numbers.to_owned();
+    for index in 0..bit_length {
+        let value = criteria(&bytes_numbers, index);
+        if bytes_numbers.iter().all(|bytes| bytes[index] != value) {
+            continue;
+        }
+        bytes_numbers.retain(|bytes| bytes[index] == value);
+        if bytes_numbers.len() == 1 {
+            break;
+        }
+    }
+    bytes_numbers[0].clone()
+}
+
+fn bytes_to_i32(bytes: Vec<u8>) -> i32 {
+    let mut num = 0;
+    for b in bytes {
+        num <<= 1;
+        num += (b - b'0') as i32;
+    }
+    num
+}
+
+pub fn diagnose_life_support(bytes_numbers: &[Vec<u8>]) -> (i32, i32) {
+    let bit_length = bytes_numbers[0].len();
+
+    let oxygen = filter_by_criteria(bytes_numbers, bit_length, &get_most_common_value);
+    let co2 = filter_by_criteria(bytes_numbers, bit_length, &get_least_common_value);
+
+    (bytes_to_i32(oxygen), bytes_to_i32(co2))
+}
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> ParseResult<Vec<Vec<u8>>> {
+        to_bytes_numbers(input)
+    }
+
+    fn part1(bytes_numbers: &Vec<Vec<u8>>) -> Answer {
+        let (gamma, epsilon) = diagnose_power_consumption(bytes_numbers);
+        (gamma * epsilon).into()
+    }
+
+    fn part2(bytes_numbers: &Vec<Vec<u8>>) -> Answer {
+        let (oxygen, co2) = diagnose_life_support(bytes_numbers);
+        (oxygen * co2).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diagnose_life_support, diagnose_power_consumption, to_bytes_numbers};
+
+    #[test]
+    fn test_diagnose_power_consumption() {
+        let data = "00100
+                    11110
+                    10110
+                    10111
+                    10101
+                    01111
+                    00111
+                    11100
+                    10000
+                    11001
+                    00010
+                    01010";
+        let (gamma, epsilon) = diagnose_power_consumption(&to_bytes_numbers(data).unwrap());
+        assert_eq!(22, gamma);
+        assert_eq!(9, epsilon);
+    }
+
+    #[test]
+    fn test_diagnose_life_support() {
+        let data = "00100
+                    11110
+                    10110
+                    10111
+                    10101
+                    01111
+                    00111
+                    11100
+                    10000
+                    11001
+                    00010
+                    01010";
+        let (oxygen, co2) = diagnose_life_support(&to_bytes_numbers(data).unwrap());
+        assert_eq!(23, oxygen);
+        assert_eq!(10, co2);
+    }
+
+    #[test]
+    fn test_ties_and_duplicates() {
+        let error = to_bytes_numbers("110\n101").unwrap_err();
+        assert_eq!("1:2: expected a most common bit, found `1`", error.to_string());
+        let bytes_numbers = to_bytes_numbers("10\n10\n10").unwrap();
+        assert_eq!((2, 1), diagnose_power_consumption(&bytes_numbers));
+        assert_eq!((2, 2), diagnose_life_support(&bytes_numbers));
+    }
+}
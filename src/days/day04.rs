@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{parse, Answer, Error, ParseResult, Solution};
+
+pub struct Day04;
+
+#[derive(Clone)]
+pub struct Board {
+    num_to_index: HashMap<u8, usize>,
+    h_lines: [u8; 5],
+    v_lines: [u8; 5],
+    remain_sum: i32,
+    done: bool,
+}
+
+impl Board {
+    fn new(data: &[u8]) -> Board {
+        let num_to_index: HashMap<u8, usize> =
+            data.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+        let v_lines = [0; 5];
+        let h_lines = [0; 5];
+        let remain_sum: i32 = data.iter().map(|&n| n as i32).sum();
+        Board {
+            num_to_index,
+            v_lines,
+            h_lines,
+            remain_sum,
+            done: false,
+        }
+    }
+    fn check_and_mark(&mut self, num: u8) -> bool {
+        if !self.num_to_index.contains_key(&num) {
+            return false;
+        }
+        let index = self.num_to_index.get(&num).unwrap();
+
+        let (i, j) = (index / 5, index % 5);
+        self.h_lines[i] += 1;
+        self.v_lines[j] += 1;
+        self.remain_sum -= num as i32;
+
+        if self.h_lines[i] == 5 || self.v_lines[j] == 5 {
+            self.done = true;
+        }
+
+        self.done
+    }
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut numbers = [0; 25];
+        for (&number, &index) in &self.num_to_index {
+            numbers[index] = number;
+        }
+        for row in numbers.chunks(5) {
+            let row: Vec<String> = row.iter().map(|number| format!("{:>2}", number)).collect();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+fn read_input(input: &str) -> ParseResult<(Vec<u8>, Vec<Board>)> {
+    let sections = parse::sections(input);
+    let (num_str, boards_str) = match sections.split_first() {
+        Some((num_str, boards_str)) if !boards_str.is_empty() => (num_str, boards_str),
+        _ => return Err(parse::sections_error(input, &sections, 2, "Input should have numbers and boards")),
+    };
+    let picked_nums: Vec<u8> = parse::integers(input, num_str, "expected a number")?;
+    if let Some(repeated) = (1..picked_nums.len()).find(|&i| picked_nums[..i].contains(&picked_nums[i])) {
+        let token = num_str.split(',').map(str::trim).filter(|token| !token.is_empty()).nth(repeated);
+        return Err(Error::parse(input, token.unwrap_or(num_str), "expected every number drawn once"));
+    }
+    let mut boards: Vec<Board> = vec![];
+    for b_str in boards_str {
+        let board: Vec<u8> = b_str
+            .split_ascii_whitespace()
+            .map(|token| parse::number(input, token, "expected a number"))
+            .collect::<ParseResult<_>>()?;
+        if board.len() != 25 {
+            return Err(Error::parse(input, b_str, "A board should have 5x5 numbers"));
+        }
+        let board = Board::new(&board);
+        if board.num_to_index.len() != 25 {
+            return Err(Error::parse(input, b_str, "A board should have 25 different numbers"));
+        }
+        // Both parts need every board to win at some point.
+        let mut played = board.clone();
+        if !picked_nums.iter().any(|&num| played.check_and_mark(num)) {
+            return Err(Error::parse(input, b_str, "A board should win with the numbers drawn"));
+        }
+
+        boards.push(board);
+    }
+
+    Ok((picked_nums, boards))
+}
+
+pub fn bingo_to_win(picked_nums: &[u8], boards: &[Board]) -> i32 {
+    let mut boards = boards.to_vec();
+    for &num in picked_nums {
+        for b in &mut boards {
+            if b.check_and_mark(num) {
+                return b.remain_sum * (num as i32);
+            }
+        }
+    }
+    unreachable!("Every board wins, which is checked while parsing");
+}
+
+pub fn bingo_to_lose(picked_nums: &[u8], boards: &[Board]) -> i32 {
+    let mut boards = boards.to_vec();
+    let mut completed = boards.len();
+    for &num in picked_nums {
+        for board in &mut boards {
+            if !board.done && board.check_and_mark(num) {
+                completed -= 1;
+                if completed == 0 {
+                    return board.remain_sum * (num as i32);
+                }
+            }
+        }
+    }
+    unreachable!("Every board wins, which is checked while parsing");
+}
+
+impl Solution for Day04 {
+    type Input = (Vec<u8>, Vec<Board>);
+
+    fn parse(input: &str) -> ParseResult<(Vec<u8>, Vec<Board>)> {
+        read_input(input)
+    }
+
+    fn part1((picked_nums, boards): &(Vec<u8>, Vec<Board>)) -> Answer {
+        bingo_to_win(picked_nums, boards).into()
+    }
+
+    fn part2((picked_nums, boards): &(Vec<u8>, Vec<Board>)) -> Answer {
+        bingo_to_lose(picked_nums, boards).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::read_file_to_string;
+
+    use super::{bingo_to_lose, bingo_to_win, read_input};
+
+    #[test]
+    fn test_bingo_to_win() {
+        let (picked_nums, boards) = read_input(&read_file_to_string("day04-small").unwrap()).unwrap();
+        let score = bingo_to_win(&picked_nums, &boards);
+        assert_eq!(score, 4512);
+    }
+
+    #[test]
+    fn test_bingo_to_lose() {
+        let (picked_nums, boards) = read_input(&read_file_to_string("day04-small").unwrap()).unwrap();
+        let score = bingo_to_lose(&picked_nums, &boards);
+        assert_eq!(score, 1924);
+    }
+
+    #[test]
+    fn test_invalid_games() {
+        let board = "1  2  3  4  5\n 6  7  8  9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25";
+        let error = |input: &str| read_input(input).err().map(|why| why.to_string());
+        assert_eq!(None, error(&format!("1,2,3,4,5\n\n{}", board)));
+        assert_eq!(
+            Some("1:5: expected every number drawn once, found `1`".to_owned()),
+            error(&format!("1,2,1\n\n{}", board))
+        );
+        assert_eq!(
+            Some(format!("3:1: A board should win with the numbers drawn, found `{}`", board)),
+            error(&format!("1,2,3,4\n\n{}", board))
+        );
+        let repeated = board.replace("25", " 1");
+        assert_eq!(
+            Some(format!("3:1: A board should have 25 different numbers, found `{}`", repeated)),
+            error(&format!("1,2,3,4,5\n\n{}", repeated))
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use crate::{
+    export::{self, Image},
+    parse,
+    stream::{Lines, StreamingSolution},
+    Answer, Coordinate, Error, Grid, ParseResult, Segment, Solution,
+};
+
+pub struct Day05;
+
+trait SegmentMarker {
+    fn mark(&self, record: &mut HashMap<Coordinate, i32>, diagonal: bool);
+}
+
+impl SegmentMarker for Segment {
+    fn mark(&self, record: &mut HashMap<Coordinate, i32>, diagonal: bool) {
+        if !diagonal && !self.is_horizontal() && !self.is_vertical() {
+            return;
+        }
+        for point in self.points() {
+            *record.entry(point).or_default() += 1;
+        }
+    }
+}
+
+fn get_segment(input: &str, line: &str) -> ParseResult<Segment> {
+    const MESSAGE: &str = "Cannot parse line as `x1,y1 -> x2,y2`";
+    let value = |token: &str| match token.bytes().all(|b| b.is_ascii_digit()) {
+        true => parse::number(input, token, "coordinate out of range"),
+        false => Err(Error::parse(input, line, MESSAGE)),
+    };
+    let point = |text: &str| {
+        let (x, y) = parse::split_pair(input, text, ",", MESSAGE)?;
+        Ok(Coordinate::new(value(x)?, value(y)?))
+    };
+    let (from, to) = parse::rule(input, line, MESSAGE)?;
+    Ok(Segment::new(point(from)?, point(to)?))
+}
+
+fn get_segments(input: &str) -> ParseResult<Vec<Segment>> {
+    parse::each_line(input, |line| get_segment(input, line))
+}
+
+/// How many lines cross every point, from the origin to the farthest point.
+fn heat_map(mark_count: &HashMap<Coordinate, i32>) -> Grid<u32> {
+    let width = mark_count.keys().map(|point| point.x + 1).max().unwrap_or(0) as usize;
+    let height = mark_count.keys().map(|point| point.y + 1).max().unwrap_or(0) as usize;
+    Grid::from_fn(height, width, |(y, x)| {
+        mark_count.get(&Coordinate::new(x as i32, y as i32)).copied().unwrap_or(0) as u32
+    })
+}
+
+pub fn get_overlap_count(segments: &[Segment], diagonal: bool) -> usize {
+    let mut mark_count = HashMap::new();
+    for seg in segments {
+        seg.mark(&mut mark_count, diagonal);
+    }
+    let name = if diagonal { "day05-part2-overlaps" } else { "day05-part1-overlaps" };
+    export::save_with(name, || Image::Pgm(export::levels(&heat_map(&mark_count))));
+    mark_count.values().filter(|&value| *value > 1).count()
+}
+
+impl Solution for Day05 {
+    type Input = Vec<Segment>;
+
+    fn parse(input: &str) -> ParseResult<Vec<Segment>> {
+        get_segments(input)
+    }
+
+    fn part1(segments: &Vec<Segment>) -> Answer {
+        get_overlap_count(segments, false).into()
+    }
+
+    fn part2(segments: &Vec<Segment>) -> Answer {
+        get_overlap_count(segments, true).into()
+    }
+}
+
+/// Marks the segments as they are read, without keeping them: the memory grows with the area
+/// the lines cover, not with how many there are.
+impl StreamingSolution for Day05 {
+    fn solve_stream(lines: Lines, parts: &[u8]) -> ParseResult<Vec<Answer>> {
+        let mut marks: Vec<(bool, HashMap<Coordinate, i32>)> = parts
+            .iter()
+            .map(|&part| match part {
+                1 => Ok((false, HashMap::new())),
+                2 => Ok((true, HashMap::new())),
+                part => Err(Error::UnknownPart { part }),
+            })
+            .collect::<ParseResult<_>>()?;
+        for segment in lines.records(|line| get_segment(line, line)) {
+            let segment = segment?;
+            for (diagonal, mark_count) in &mut marks {
+                segment.mark(mark_count, *diagonal);
+            }
+        }
+        Ok(marks
+            .iter()
+            .map(|(_, mark_count)| mark_count.values().filter(|&value| *value > 1).count().into())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::{
+        stream::{Lines, StreamingSolution},
+        Answer,
+    };
+
+    use super::{get_overlap_count, get_segments, Day05};
+
+    #[test]
+    fn test_get_overlap_count() {
+        let data = "0,9 -> 5,9
+                    8,0 -> 0,8
+                    9,4 -> 3,4
+                    2,2 -> 2,1
+                    7,0 -> 7,4
+                    6,4 -> 2,0
+                    0,9 -> 2,9
+                    3,4 -> 1,4
+                    0,0 -> 8,8
+                    5,5 -> 8,2";
+        let segments = get_segments(data).unwrap();
+        assert_eq!(5, get_overlap_count(&segments, false));
+        assert_eq!(12, get_overlap_count(&segments, true));
+
+        let lines = Lines::new(Cursor::new(data.to_owned()), "memory");
+        assert_eq!(vec![Answer::Number(5), Answer::Number(12)], Day05::solve_stream(lines, &[1, 2]).unwrap());
+    }
+}
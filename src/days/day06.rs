@@ -0,0 +1,93 @@
+use crate::{parse, Answer, Error, ParseResult, Solution};
+
+pub struct Day06;
+
+const GROW_UP_DAYS: usize = 9;
+const BIRTH_PERIOD: usize = 7;
+
+fn count_laternfish_children(life_days: usize, children_count: &[i128]) -> i128 {
+    let mut count: i128 = 0;
+
+    let mut child_life_days = life_days - GROW_UP_DAYS;
+    // count the child and its children
+    count += 1 + children_count[child_life_days];
+    while child_life_days >= BIRTH_PERIOD {
+        child_life_days -= BIRTH_PERIOD;
+        count += 1 + children_count[child_life_days];
+    }
+    count
+}
+
+fn get_timers(input: &str) -> ParseResult<Vec<usize>> {
+    parse::comma_separated(input, |token| match token.parse::<usize>() {
+        Ok(value) if value < GROW_UP_DAYS => Ok(value),
+        _ => Err(Error::parse(input, token, "expected a timer from 0 to 8")),
+    })
+}
+
+pub fn count_laternfish(timers: &[usize], world_days: usize) -> i128 {
+    // children_count is a DP table
+    let max_live_days = world_days + GROW_UP_DAYS;
+    let mut children_count = vec![0i128; max_live_days];
+    for life_days in GROW_UP_DAYS..max_live_days {
+        children_count[life_days] = count_laternfish_children(life_days, &children_count);
+    }
+
+    timers
+        .iter()
+        .map(|value| GROW_UP_DAYS - (value + 1) + world_days)
+        .map(|life_days| 1 + children_count[life_days])
+        .sum::<i128>()
+}
+
+impl Solution for Day06 {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> ParseResult<Vec<usize>> {
+        get_timers(input)
+    }
+
+    fn part1(timers: &Vec<usize>) -> Answer {
+        count_laternfish(timers, 80).into()
+    }
+
+    fn part2(timers: &Vec<usize>) -> Answer {
+        count_laternfish(timers, 256).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{count_laternfish, get_timers};
+
+    /// Simulates every fish, one day at a time.
+    fn simulate(timers: &[usize], days: usize) -> i128 {
+        let mut fish = timers.to_vec();
+        for _ in 0..days {
+            let born = fish.iter().filter(|&&timer| timer == 0).count();
+            fish = fish.iter().map(|&timer| if timer == 0 { 6 } else { timer - 1 }).collect();
+            fish.extend(std::iter::repeat_n(8, born));
+        }
+        fish.len() as i128
+    }
+
+    #[test]
+    fn test_count_laternfish() {
+        let data = get_timers("3,4,3,1,2").unwrap();
+        assert_eq!(26, count_laternfish(&data, 18));
+        assert_eq!(5934, count_laternfish(&data, 80));
+        assert_eq!(26984457539, count_laternfish(&data, 256));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_count_laternfish_matches_simulation(
+            timers in prop::collection::vec(0usize..9, 0..20),
+            days in 0usize..50,
+        ) {
+            prop_assert_eq!(simulate(&timers, days), count_laternfish(&timers, days));
+        }
+    }
+}
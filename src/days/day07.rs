@@ -0,0 +1,90 @@
+use crate::{parse, Answer, Error, ParseResult, Solution};
+
+pub struct Day07;
+
+fn get_crab_positions(input: &str) -> ParseResult<Vec<i32>> {
+    let positions: Vec<i32> = parse::integers(input, input, "expected a position")?;
+    if positions.is_empty() {
+        return Err(Error::parse(input, input.trim(), "expected crab positions"));
+    }
+    Ok(positions)
+}
+
+fn find_median(positions: &mut [i32]) -> i32 {
+    positions.sort_unstable();
+
+    let mid = positions.len() / 2;
+    positions[mid]
+}
+
+fn calc_distance_sum(positions: &[i32], target: i32) -> i32 {
+    positions.iter().map(|&val| i32::abs(val - target)).sum()
+}
+
+fn find_average_floor(positions: &[i32]) -> i32 {
+    let avg = positions.iter().sum::<i32>() as f64 / positions.len() as f64;
+    avg.floor() as i32
+}
+
+fn calc_weighted_distance_sum(positions: &[i32], target: i32) -> i32 {
+    positions
+        .iter()
+        .map(|&pos| {
+            let distance = i32::abs(pos - target);
+            distance * (distance + 1) / 2
+        })
+        .sum()
+}
+
+pub fn align_crabs(positions: &[i32]) -> i32 {
+    let mut positions = positions.to_vec();
+    let median = find_median(&mut positions);
+    calc_distance_sum(&positions, median)
+}
+
+pub fn weighted_align_crabs(positions: &[i32]) -> i32 {
+    let avg = find_average_floor(positions);
+    i32::min(
+        calc_weighted_distance_sum(positions, avg),
+        calc_weighted_distance_sum(positions, avg + 1),
+    )
+}
+
+impl Solution for Day07 {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> ParseResult<Vec<i32>> {
+        get_crab_positions(input)
+    }
+
+    fn part1(positions: &Vec<i32>) -> Answer {
+        align_crabs(positions).into()
+    }
+
+    fn part2(positions: &Vec<i32>) -> Answer {
+        weighted_align_crabs(positions).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{align_crabs, calc_distance_sum, get_crab_positions, weighted_align_crabs};
+
+    #[test]
+    fn test_align_crabs() {
+        let data = get_crab_positions("16,1,2,0,4,2,7,1,2,14").unwrap();
+        assert_eq!(37, align_crabs(&data));
+        assert_eq!(168, weighted_align_crabs(&data));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_align_crabs_is_minimal(positions in prop::collection::vec(0..500, 1..100)) {
+            let (low, high) = (*positions.iter().min().unwrap(), *positions.iter().max().unwrap());
+            let cheapest = (low..=high).map(|target| calc_distance_sum(&positions, target)).min().unwrap();
+            prop_assert_eq!(cheapest, align_crabs(&positions));
+        }
+    }
+}
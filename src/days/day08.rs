@@ -0,0 +1,187 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{parse, Answer, Error, ParseResult, Solution};
+
+pub struct Day08;
+
+pub type Entry = (Vec<String>, Vec<String>);
+
+fn parse_digits(input: &str, pattern: &str, count: usize) -> ParseResult<Vec<String>> {
+    let digits: Vec<String> = pattern
+        .split_ascii_whitespace()
+        .map(|token| {
+            if let Some((i, c)) = token.char_indices().find(|(_, c)| !('a'..='g').contains(c)) {
+                return Err(Error::parse(input, &token[i..i + c.len_utf8()], "expected a segment from a to g"));
+            }
+            let mut chars: Vec<char> = token.chars().collect();
+            chars.sort_by(|a, b| b.cmp(a));
+            Ok(chars.iter().collect())
+        })
+        .collect::<ParseResult<_>>()?;
+    if digits.len() != count {
+        return Err(Error::parse(input, pattern, format!("expected {} digits", count)));
+    }
+    Ok(digits)
+}
+
+fn get_entries(input: &str) -> ParseResult<Vec<Entry>> {
+    parse::each_line(input, |entry| {
+        let (patterns, output_digits) = entry
+            .split_once('|')
+            .ok_or_else(|| Error::parse(input, entry, "Where is my IO?"))?;
+        let entry_digits = (
+            parse_digits(input, patterns.trim(), 10)?,
+            parse_digits(input, output_digits.trim(), 4)?,
+        );
+        match get_output_digits(&entry_digits) {
+            Some(_) => Ok(entry_digits),
+            None => Err(Error::parse(input, entry, "expected the ten digits, wired the same way")),
+        }
+    })
+}
+
+pub fn count_uqique_digits(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .flat_map(|(_, output_digits)| output_digits)
+        .filter(|digit_str| matches!(digit_str.len(), 2 | 3 | 4 | 7))
+        .count()
+}
+
+/// The digit of every pattern, or `None` when the patterns aren't the ten digits.
+fn reasoning_digits(patterns: &[String]) -> Option<HashMap<String, u8>> {
+    // Use "one" and "four" as filters for (2, 3, 5) and (0, 6, 9)
+    let one = patterns.iter().find(|ptn| ptn.len() == 2)?;
+    let four = patterns.iter().find(|ptn| ptn.len() == 4)?;
+    let pattern_to_digits: HashMap<String, u8> = patterns
+        .iter()
+        .map(|ptn| {
+            let pattern = ptn.to_owned();
+            match pattern.len() {
+                2 => Some((pattern, 1)),
+                3 => Some((pattern, 7)),
+                4 => Some((pattern, 4)),
+                7 => Some((pattern, 8)),
+                len => match (
+                    len,
+                    pattern.chars().filter(|c| one.contains(*c)).count(),
+                    pattern.chars().filter(|c| four.contains(*c)).count(),
+                ) {
+                    (5, 1, 2) => Some((pattern, 2)),
+                    (5, 2, 3) => Some((pattern, 3)),
+                    (5, 1, 3) => Some((pattern, 5)),
+                    (6, 2, 3) => Some((pattern, 0)),
+                    (6, 1, 3) => Some((pattern, 6)),
+                    (6, 2, 4) => Some((pattern, 9)),
+                    _ => None,
+                },
+            }
+        })
+        .collect::<Option<_>>()?;
+    let digits: HashSet<u8> = pattern_to_digits.values().copied().collect();
+    (digits.len() == 10).then_some(pattern_to_digits)
+}
+
+/// The four digits of the output, or `None` when they can't be worked out from the patterns.
+fn get_output_digits((patterns, output_digits): &Entry) -> Option<u32> {
+    let pattern_to_digits = reasoning_digits(patterns)?;
+
+    output_digits
+        .iter()
+        .map(|pattern| pattern_to_digits.get(pattern).copied())
+        .enumerate()
+        .try_fold(0, |sum, (i, d)| Some(sum + d? as u32 * 10u32.pow(3 - i as u32)))
+}
+
+pub fn addup_output_digits(entries: &[Entry]) -> u32 {
+    entries
+        .iter()
+        .map(|entry| get_output_digits(entry).expect("entries are checked while parsing"))
+        .sum()
+}
+
+impl Solution for Day08 {
+    type Input = Vec<Entry>;
+
+    fn parse(input: &str) -> ParseResult<Vec<Entry>> {
+        get_entries(input)
+    }
+
+    fn part1(entries: &Vec<Entry>) -> Answer {
+        count_uqique_digits(entries).into()
+    }
+
+    fn part2(entries: &Vec<Entry>) -> Answer {
+        addup_output_digits(entries).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{addup_output_digits, count_uqique_digits, get_entries, get_output_digits};
+
+    #[test]
+    fn test_count_uqique_digits() {
+        let data =
+            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+            edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+            fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+            fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+            aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+            fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+            dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+            bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+            egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+            gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+        assert_eq!(26, count_uqique_digits(&get_entries(data).unwrap()));
+    }
+
+    #[test]
+    fn test_get_output_digits() {
+        let data =
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+        assert_eq!(Some(5353), get_output_digits(&get_entries(data).unwrap()[0]));
+        let data_set = vec![
+            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe",
+            "edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc",
+            "fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg",
+            "fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb",
+            "aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea",
+            "fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb",
+            "dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe",
+            "bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef",
+            "egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb",
+            "gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce",
+        ];
+        let ans_set = vec![8394, 9781, 1197, 9361, 4873, 8418, 4548, 1625, 8717, 4315];
+        for i in 0..data_set.len() {
+            assert_eq!(Some(ans_set[i]), get_output_digits(&get_entries(data_set[i]).unwrap()[0]))
+        }
+    }
+
+    #[test]
+    fn test_unknown_wiring() {
+        let data = "ab ab ab ab ab ab ab ab ab ab | ab ab ab ab";
+        let error = get_entries(data).unwrap_err();
+        assert_eq!(format!("1:1: expected the ten digits, wired the same way, found `{}`", data), error.to_string());
+        let data = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb abc";
+        assert!(get_entries(data).is_err());
+    }
+
+    #[test]
+    fn test_addup_output_digits() {
+        let data =
+            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+            edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+            fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+            fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+            aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+            fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+            dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+            bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+            egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+            gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+
+        assert_eq!(61229, addup_output_digits(&get_entries(data).unwrap()));
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    export::{self, Image},
+    union_find::{self, Components},
+    parse,
+    visualize::{self, Color, Style},
+    Answer, Grid, ParseResult, Solution,
+};
+
+pub struct Day09;
+
+fn is_valley(height_map: &Grid<u8>, position: (usize, usize)) -> bool {
+    height_map
+        .neighbours4(position)
+        .all(|neighbour| height_map[position] < height_map[neighbour])
+}
+
+fn get_height_map(input: &str) -> ParseResult<Grid<u8>> {
+    parse::digit_grid(input, 0..=9, "input should be 0-9 digit")
+}
+
+pub fn calc_risk_of_low_points(height_map: &Grid<u8>) -> i32 {
+    height_map
+        .positions()
+        .filter(|&position| is_valley(height_map, position))
+        .map(|position| (1 + height_map[position]) as i32)
+        .sum()
+}
+
+/// The first `basins` basins in colour.
+fn show_basins(height_map: &Grid<u8>, labels: &Grid<Option<usize>>, basins: usize) {
+    const COLORS: [Color; 6] = [Color::Red, Color::Green, Color::Yellow, Color::Blue, Color::Magenta, Color::Cyan];
+    visualize::show_with(|| {
+        let cells = Grid::from_fn(height_map.height(), height_map.width(), |position| {
+            let symbol = char::from(b'0' + height_map[position]);
+            match labels[position] {
+                Some(id) if id < basins => Style::new(symbol, COLORS[id % COLORS.len()]),
+                _ => Style::new(symbol, Color::Shade(height_map[position] / 3)),
+            }
+        });
+        visualize::Frame { title: format!("Day 09, {} basins", basins), cells }
+    });
+}
+
+/// Every basin in its own colour, the ridges of 9 in black.
+fn basin_image(labels: &Grid<Option<usize>>) -> Grid<export::Rgb> {
+    labels.map(|label| match label {
+        Some(id) => export::PALETTE[id % export::PALETTE.len()],
+        None => export::BLACK,
+    })
+}
+
+pub fn calc_top3_basin(height_map: &Grid<u8>) -> i32 {
+    let Components { labels, mut sizes } = union_find::label_components(height_map, |&height| height != 9);
+    for basins in 1..=sizes.len() {
+        show_basins(height_map, &labels, basins);
+    }
+    export::save_with("day09-basins", || Image::Ppm(basin_image(&labels)));
+    sizes.sort_unstable();
+    sizes.iter().rev().take(3).product::<usize>() as i32
+}
+
+impl Solution for Day09 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> ParseResult<Grid<u8>> {
+        get_height_map(input)
+    }
+
+    fn part1(height_map: &Grid<u8>) -> Answer {
+        calc_risk_of_low_points(height_map).into()
+    }
+
+    fn part2(height_map: &Grid<u8>) -> Answer {
+        calc_top3_basin(height_map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calc_risk_of_low_points, calc_top3_basin, get_height_map};
+
+    #[test]
+    fn test_calc_risk_of_low_points() {
+        let input = "2199943210
+                    3987894921
+                    9856789892
+                    8767896789
+                    9899965678";
+        assert_eq!(15, calc_risk_of_low_points(&get_height_map(input).unwrap()));
+    }
+
+    #[test]
+    fn test_calc_top3_basin() {
+        let input = "2199943210
+                    3987894921
+                    9856789892
+                    8767896789
+                    9899965678";
+        assert_eq!(1134, calc_top3_basin(&get_height_map(input).unwrap()));
+    }
+}
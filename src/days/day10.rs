@@ -0,0 +1,212 @@
+use crate::{
+    parse,
+    stream::{Lines, StreamingSolution},
+    Answer, Error, ParseResult, Solution,
+};
+
+pub struct Day10;
+
+enum ErrorType {
+    Corrupted,
+    Incomplete,
+    Complete,
+}
+
+fn get_paired(ch: char) -> char {
+    match ch {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        ')' => '(',
+        ']' => '[',
+        '}' => '{',
+        '>' => '<',
+        _ => unreachable!(),
+    }
+}
+
+fn syntax_check(line: &str) -> (ErrorType, Vec<char>) {
+    let mut stack: Vec<char> = vec![];
+    for ch in line.chars() {
+        match ch {
+            '(' | '[' | '{' | '<' => stack.push(ch),
+            ch => match stack.pop() {
+                Some(top) if top == get_paired(ch) => {}
+                _ => {
+                    return (ErrorType::Corrupted, vec![ch]);
+                }
+            },
+        }
+    }
+    match stack.is_empty() {
+        true => (ErrorType::Complete, stack),
+        false => (ErrorType::Incomplete, stack),
+    }
+}
+
+fn get_line(input: &str, line: &str) -> ParseResult<String> {
+    match line.char_indices().find(|&(_, c)| !"()[]{}<>".contains(c)) {
+        Some((i, c)) => Err(Error::parse(input, &line[i..i + c.len_utf8()], "expected a bracket")),
+        None => Ok(line.to_owned()),
+    }
+}
+
+fn get_lines(input: &str) -> ParseResult<Vec<String>> {
+    parse::each_line(input, |line| get_line(input, line))
+}
+
+/// The score of a corrupted line, 0 for an incomplete one.
+fn corrupted_points(line: &str) -> u32 {
+    match syntax_check(line) {
+        (ErrorType::Corrupted, mismatch) => match mismatch[0] {
+            ')' => 3,
+            ']' => 57,
+            '}' => 1197,
+            '>' => 25137,
+            _ => unreachable!(),
+        },
+        _ => 0,
+    }
+}
+
+/// The score of completing an incomplete line, 0 for a corrupted or complete one.
+fn incomplete_points(line: &str) -> u128 {
+    match syntax_check(line) {
+        (ErrorType::Incomplete, mismatch) => mismatch
+            .iter()
+            .rev()
+            .fold(0, |sum, ch| sum * 5 + match get_paired(*ch) {
+                ')' => 1,
+                ']' => 2,
+                '}' => 3,
+                '>' => 4,
+                _ => unreachable!(),
+            }),
+        _ => 0,
+    }
+}
+
+/// The middle of the scores, 0 when there are none.
+fn middle_score(scores: &mut [u128]) -> u128 {
+    scores.sort_unstable();
+    scores.get(scores.len() / 2).copied().unwrap_or(0)
+}
+
+pub fn calc_corrupted_points(lines: &[String]) -> u32 {
+    lines.iter().map(|line| corrupted_points(line)).sum()
+}
+
+pub fn calc_incomplete_points(lines: &[String]) -> u128 {
+    let mut scores: Vec<u128> = lines.iter().map(|line| incomplete_points(line)).filter(|score| *score > 0).collect();
+    middle_score(&mut scores)
+}
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> ParseResult<Vec<String>> {
+        get_lines(input)
+    }
+
+    fn part1(lines: &Vec<String>) -> Answer {
+        calc_corrupted_points(lines).into()
+    }
+
+    fn part2(lines: &Vec<String>) -> Answer {
+        calc_incomplete_points(lines).into()
+    }
+}
+
+/// Scores the lines as they are read. The middle completion score needs every score, so this
+/// keeps one number per incomplete line, not the lines.
+impl StreamingSolution for Day10 {
+    fn solve_stream(lines: Lines, parts: &[u8]) -> ParseResult<Vec<Answer>> {
+        let (mut corrupted, mut incomplete) = (0, vec![]);
+        for line in lines.records(|line| get_line(line, line)) {
+            let line = line?;
+            if parts.contains(&1) {
+                corrupted += corrupted_points(&line);
+            }
+            if parts.contains(&2) {
+                match incomplete_points(&line) {
+                    0 => {}
+                    score => incomplete.push(score),
+                }
+            }
+        }
+        parts
+            .iter()
+            .map(|&part| match part {
+                1 => Ok(corrupted.into()),
+                2 => Ok(middle_score(&mut incomplete).into()),
+                part => Err(Error::UnknownPart { part }),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::{
+        stream::{Lines, StreamingSolution},
+        Answer,
+    };
+
+    use super::{calc_corrupted_points, calc_incomplete_points, get_lines, Day10};
+
+    #[test]
+    fn test_calc_corrupted_points() {
+        let input = "[({(<(())[]>[[{[]{<()<>>
+                    [(()[<>])]({[<{<<[]>>(
+                    {([(<{}[<>[]}>{[]{[(<()>
+                    (((({<>}<{<{<>}{[]{[]{}
+                    [[<[([]))<([[{}[[()]]]
+                    [{[{({}]{}}([{[{{{}}([]
+                    {<[[]]>}<{[{[{[]{()[[[]
+                    [<(<(<(<{}))><([]([]()
+                    <{([([[(<>()){}]>(<<{{
+                    <{([{{}}[<[[[<>{}]]]>[]]";
+        assert_eq!(26397, calc_corrupted_points(&get_lines(input).unwrap()))
+    }
+
+    #[test]
+    fn test_calc_incomplete_points() {
+        let input = "[({(<(())[]>[[{[]{<()<>>
+                    [(()[<>])]({[<{<<[]>>(
+                    {([(<{}[<>[]}>{[]{[(<()>
+                    (((({<>}<{<{<>}{[]{[]{}
+                    [[<[([]))<([[{}[[()]]]
+                    [{[{({}]{}}([{[{{{}}([]
+                    {<[[]]>}<{[{[{[]{()[[[]
+                    [<(<(<(<{}))><([]([]()
+                    <{([([[(<>()){}]>(<<{{
+                    <{([{{}}[<[[[<>{}]]]>[]]";
+        assert_eq!(288957, calc_incomplete_points(&get_lines(input).unwrap()));
+
+        let lines = Lines::new(Cursor::new(input.to_owned()), "memory");
+        assert_eq!(vec![Answer::Number(26397), Answer::Number(288957)], Day10::solve_stream(lines, &[1, 2]).unwrap());
+    }
+
+    #[test]
+    fn test_complete_and_blank_lines() {
+        let input = "()
+
+                     [<>]";
+        let lines = get_lines(input).unwrap();
+        assert_eq!(2, lines.len());
+        assert_eq!(0, calc_corrupted_points(&lines));
+        assert_eq!(0, calc_incomplete_points(&lines));
+
+        let lines = Lines::new(Cursor::new("()\n\n(\n"), "memory");
+        assert_eq!(vec![Answer::Number(0), Answer::Number(1)], Day10::solve_stream(lines, &[1, 2]).unwrap());
+    }
+
+    #[test]
+    fn test_long_incomplete_line() {
+        let line = "<".repeat(40);
+        assert_eq!(5u128.pow(40) - 1, calc_incomplete_points(&[line]));
+    }
+}
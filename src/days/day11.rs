@@ -0,0 +1,130 @@
+use std::collections::VecDeque;
+
+use crate::{
+    parse,
+    visualize::{self, Color, Style},
+    Answer, Grid, ParseResult, Solution,
+};
+
+pub struct Day11;
+
+fn get_grid(input: &str) -> ParseResult<Grid<u8>> {
+    parse::digit_grid(input, 0..=9, "energy level should be from 0-9")
+}
+
+fn increase_and_check_energy(grid: &mut Grid<u8>, position: (usize, usize)) -> bool {
+    if grid[position] > 9 {
+        return false;
+    }
+    grid[position] += 1;
+    grid[position] > 9
+}
+
+fn step_routine(grid: &mut Grid<u8>) -> u32 {
+    let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+
+    for position in grid.positions() {
+        if increase_and_check_energy(grid, position) {
+            queue.push_back(position);
+        }
+    }
+
+    while let Some(position) = queue.pop_front() {
+        for neighbour in grid.neighbours8(position) {
+            if increase_and_check_energy(grid, neighbour) {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    let mut flashes = 0;
+    for energy in grid.iter_mut() {
+        if *energy > 9 {
+            *energy = 0;
+            flashes += 1;
+        }
+    }
+    flashes
+}
+
+fn show_octopuses(grid: &Grid<u8>, step: u32) {
+    visualize::show(format!("Day 11, step {}", step), grid, |&energy| match energy {
+        0 => Style::new('*', Color::Yellow),
+        _ => Style::new(char::from(b'0' + energy), Color::Shade(energy)),
+    });
+}
+
+pub fn calc_flashes(grid: &Grid<u8>, steps: u32) -> u32 {
+    let mut grid = grid.clone();
+    (1..=steps)
+        .map(|step| {
+            let flashes = step_routine(&mut grid);
+            show_octopuses(&grid, step);
+            flashes
+        })
+        .sum()
+}
+
+pub fn calc_synchronizing_flash(grid: &Grid<u8>) -> u32 {
+    let mut grid = grid.clone();
+    let total_octopuses = grid.len() as u32;
+    for step in 1..u32::MAX {
+        let flashes = step_routine(&mut grid);
+        show_octopuses(&grid, step);
+        if flashes == total_octopuses {
+            return step;
+        }
+    }
+    unreachable!("We should be able to find a step with all octopuses lighting up");
+}
+
+impl Solution for Day11 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> ParseResult<Grid<u8>> {
+        get_grid(input)
+    }
+
+    fn part1(grid: &Grid<u8>) -> Answer {
+        calc_flashes(grid, 100).into()
+    }
+
+    fn part2(grid: &Grid<u8>) -> Answer {
+        calc_synchronizing_flash(grid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calc_flashes, calc_synchronizing_flash, get_grid};
+
+    #[test]
+    fn test_calc_flashes() {
+        let input = "5483143223
+                    2745854711
+                    5264556173
+                    6141336146
+                    6357385478
+                    4167524645
+                    2176841721
+                    6882881134
+                    4846848554
+                    5283751526";
+        assert_eq!(1656, calc_flashes(&get_grid(input).unwrap(), 100));
+    }
+
+    #[test]
+    fn test_calc_synchronizing_flash() {
+        let input = "5483143223
+                    2745854711
+                    5264556173
+                    6141336146
+                    6357385478
+                    4167524645
+                    2176841721
+                    6882881134
+                    4846848554
+                    5283751526";
+        assert_eq!(195, calc_synchronizing_flash(&get_grid(input).unwrap()));
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt;
+
+use crate::{graph, interner::Interner, parse, Answer, Error, ParseResult, Solution};
+
+pub struct Day12;
+
+/// The caves by ID, at most 64 so that the small caves a walk went through fit in a `u64`.
+pub struct Caves {
+    names: Interner,
+    neighbours: Vec<Vec<usize>>,
+    /// Bit `id` is set when cave `id` is small.
+    small: u64,
+    start: usize,
+    end: Option<usize>,
+}
+
+fn construct_graph(input: &str) -> ParseResult<Caves> {
+    let mut names = Interner::new();
+    let mut neighbours: Vec<Vec<usize>> = vec![];
+    for line in parse::lines(input) {
+        let (from, to) = parse::edge(input, line, "Expect to have two caves in a line")?;
+        let (from, to) = (names.intern(from), names.intern(to));
+        if names.len() > u64::BITS as usize {
+            return Err(Error::parse(input, line, "Expect to have at most 64 caves"));
+        }
+        neighbours.resize(names.len(), vec![]);
+        neighbours[from].push(to);
+        neighbours[to].push(from);
+    }
+    let start = names
+        .get("start")
+        .ok_or_else(|| Error::parse(input, input, "Expect to have a start cave"))?;
+    let small = names
+        .iter()
+        .filter(|(_, name)| name.chars().all(char::is_lowercase))
+        .fold(0, |small, (id, _)| small | 1 << id);
+    let end = names.get("end");
+    Ok(Caves { names, neighbours, small, start, end })
+}
+
+impl graph::Graph for Caves {
+    type Node = usize;
+
+    fn neighbours(&self, &cave: &usize) -> impl Iterator<Item = usize> {
+        self.neighbours[cave].iter().copied()
+    }
+}
+
+/// Every cave with its neighbours, by name.
+impl fmt::Display for Caves {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut caves: Vec<(&String, Vec<&String>)> = self
+            .names
+            .iter()
+            .map(|(id, name)| {
+                let mut neighbours: Vec<&String> = self.neighbours[id].iter().map(|&id| self.names.name(id)).collect();
+                neighbours.sort();
+                (name, neighbours)
+            })
+            .collect();
+        caves.sort();
+        for (cave, neighbours) in caves {
+            let neighbours: Vec<&str> = neighbours.into_iter().map(String::as_str).collect();
+            writeln!(f, "{} -> {}", cave, neighbours.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Where a walk through the caves is.
+#[derive(Clone, Copy)]
+struct Walk {
+    /// The small caves it went through, as bits.
+    visited: u64,
+    can_revisit: bool,
+}
+
+pub fn count_paths(caves: &Caves, can_revisit_small_cave: bool) -> u32 {
+    let Some(end) = caves.end else {
+        return 0;
+    };
+    // The walk once in `cave`, unless it can't go there.
+    let enter = |walk: &Walk, &cave: &usize| {
+        let bit = 1 << cave;
+        if cave == caves.start {
+            None
+        } else if caves.small & bit == 0 {
+            Some(*walk)
+        } else if walk.visited & bit == 0 {
+            Some(Walk { visited: walk.visited | bit, ..*walk })
+        } else if walk.can_revisit {
+            Some(Walk { can_revisit: false, ..*walk })
+        } else {
+            None
+        }
+    };
+    let walk = Walk { visited: 0, can_revisit: can_revisit_small_cave };
+    graph::count_paths(caves, &caves.start, &end, &walk, &enter) as u32
+}
+
+impl Solution for Day12 {
+    type Input = Caves;
+
+    fn parse(input: &str) -> ParseResult<Caves> {
+        construct_graph(input)
+    }
+
+    fn part1(caves: &Caves) -> Answer {
+        count_paths(caves, false).into()
+    }
+
+    fn part2(caves: &Caves) -> Answer {
+        count_paths(caves, true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{construct_graph, count_paths};
+
+    #[test]
+    fn test_construct_graph() {
+        let caves = construct_graph("start-A\nA-b\nb-end\nA-end").unwrap();
+        assert_eq!("A -> b, end, start\nb -> A, end\nend -> A, b\nstart -> A\n", caves.to_string());
+        assert_eq!(0, count_paths(&construct_graph("start-a").unwrap(), true));
+        let many: Vec<String> = (0..64).map(|cave| format!("start-c{}", cave)).collect();
+        assert!(construct_graph(&many.join("\n")).is_err());
+        assert!(construct_graph(&many[1..].join("\n")).is_ok());
+    }
+
+    #[test]
+    fn test_count_paths() {
+        let input1 = "start-A
+                    start-b
+                    A-c
+                    A-b
+                    b-d
+                    A-end
+                    b-end";
+        let graph1 = construct_graph(input1).unwrap();
+        assert_eq!(10, count_paths(&graph1, false));
+        assert_eq!(36, count_paths(&graph1, true));
+        let input2 = "dc-end
+                    HN-start
+                    start-kj
+                    dc-start
+                    dc-HN
+                    LN-dc
+                    HN-end
+                    kj-sa
+                    kj-HN
+                    kj-dc";
+        let graph2 = construct_graph(input2).unwrap();
+        assert_eq!(19, count_paths(&graph2, false));
+        assert_eq!(103, count_paths(&graph2, true));
+        let input3 = "fs-end
+                    he-DX
+                    fs-he
+                    start-DX
+                    pj-DX
+                    end-zg
+                    zg-sl
+                    zg-pj
+                    pj-he
+                    RW-he
+                    fs-DX
+                    pj-RW
+                    zg-RW
+                    start-pj
+                    he-WI
+                    zg-he
+                    pj-fs
+                    start-RW";
+        let graph3 = construct_graph(input3).unwrap();
+        assert_eq!(226, count_paths(&graph3, false));
+        assert_eq!(3509, count_paths(&graph3, true));
+    }
+}
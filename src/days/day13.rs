@@ -0,0 +1,282 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::{
+    bitgrid::BitGrid,
+    export::{self, Image},
+    visualize::{self, Color, Style},
+    parse, Answer, Coordinate, Error, Grid, ParseResult, Solution,
+};
+
+pub struct Day13;
+
+/// The most cells the sheet can have. Real inputs need about 1300 by 900, and the sheet is
+/// allocated up front, so bigger ones are rejected while parsing.
+const MAX_SHEET_AREA: u64 = 1 << 24;
+
+#[derive(Debug, Clone)]
+pub struct OrigamiPaper {
+    /// At `(y, x)`.
+    dots: BitGrid,
+    folds: Vec<(u32, u32)>,
+    fold_times: usize,
+}
+
+impl OrigamiPaper {
+    fn new(dots: impl IntoIterator<Item = (u32, u32)>, folds: Vec<(u32, u32)>) -> OrigamiPaper {
+        let dots: Vec<(usize, usize)> = dots.into_iter().map(|(x, y)| (y as usize, x as usize)).collect();
+        let height = dots.iter().map(|(y, _)| y + 1).max().unwrap_or(0);
+        let width = dots.iter().map(|(_, x)| x + 1).max().unwrap_or(0);
+        let mut sheet = BitGrid::new(height, width);
+        for dot in dots {
+            sheet.set(dot);
+        }
+        OrigamiPaper { dots: sheet, folds, fold_times: 0 }
+    }
+
+    fn fold(&mut self) -> bool {
+        let (fx, fy) = self.folds[self.fold_times];
+        let (fx, fy) = (fx as usize, fy as usize);
+        // The fold line stays on the sheet, in case dots lie on it.
+        let (height, width) = match (fx, fy) {
+            (0, pos) => ((pos + 1).min(self.dots.height()), self.dots.width()),
+            (pos, _) => (self.dots.height(), (pos + 1).min(self.dots.width())),
+        };
+        let mut folded = BitGrid::new(height, width);
+        for (y, x) in self.dots.iter() {
+            let dot = match (fx, fy) {
+                (0, pos) if y > pos => (2 * pos).checked_sub(y).map(|y| (y, x)),
+                (0, _) => Some((y, x)),
+                (pos, _) if x > pos => (2 * pos).checked_sub(x).map(|x| (y, x)),
+                _ => Some((y, x)),
+            };
+            if let Some(dot) = dot {
+                folded.set(dot);
+            }
+        }
+        self.dots = folded;
+        self.fold_times += 1;
+        visualize::show_with(|| self.frame());
+
+        self.fold_times < self.folds.len()
+    }
+
+    /// A copy folded along the next `count` folds, or as many as are left.
+    pub fn folded(&self, count: usize) -> OrigamiPaper {
+        let mut paper = self.clone();
+        for _ in 0..count.min(self.folds.len() - self.fold_times) {
+            paper.fold();
+        }
+        paper
+    }
+
+    pub fn dot_count(&self) -> usize {
+        self.dots.count()
+    }
+
+    /// The dots, down to the farthest one.
+    fn sheet(&self) -> BitGrid {
+        let height = self.dots.iter().map(|(y, _)| y + 1).max().unwrap_or(0);
+        let width = self.dots.iter().map(|(_, x)| x + 1).max().unwrap_or(0);
+        self.dots.crop(height, width)
+    }
+
+    fn frame(&self) -> visualize::Frame {
+        let sheet = self.sheet();
+        let sheet = Grid::from_fn(sheet.height(), sheet.width(), |position| sheet.test(position));
+        let title = format!("Day 13, fold {} of {}", self.fold_times, self.folds.len());
+        visualize::Frame::new(title, &sheet, |&dot| match dot {
+            true => Style::new('#', Color::Yellow),
+            false => Style::new('.', Color::Shade(1)),
+        })
+    }
+}
+
+impl fmt::Display for OrigamiPaper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.dots.count() == 0 {
+            true => writeln!(f, "(no dots)"),
+            false => write!(f, "{}", render(self)),
+        }
+    }
+}
+
+fn get_dot_locations(input: &str, dots: &str) -> ParseResult<HashSet<(u32, u32)>> {
+    let parse_value = |token: &str| parse::number::<u32>(input, token, "expected a number");
+    let (mut width, mut height) = (0, 0);
+    parse::lines(dots)
+        .map(|line| {
+            let (x_str, y_str) = parse::split_pair(input, line, ",", "expected a dot as `x,y`")?;
+            let (x, y) = (parse_value(x_str)?, parse_value(y_str)?);
+            width = width.max(x as u64 + 1);
+            height = height.max(y as u64 + 1);
+            if width * height > MAX_SHEET_AREA {
+                let message = format!("expected a sheet of at most {} cells", MAX_SHEET_AREA);
+                return Err(Error::parse(input, line, message));
+            }
+            Ok((x, y))
+        })
+        .collect()
+}
+
+fn get_folds(input: &str, folds: &str) -> ParseResult<Vec<(u32, u32)>> {
+    parse::each_line(folds, |line| {
+        let (dir_str, pos_str) = line
+            .split_once('=')
+            .ok_or_else(|| Error::parse(input, line, "expected a fold as `fold along x=5`"))?;
+        let pos = match pos_str.parse::<u32>() {
+            Ok(pos) if pos > 0 => pos,
+            _ => return Err(Error::parse(input, pos_str, "expected a positive fold position")),
+        };
+        match dir_str.strip_prefix("fold along ") {
+            Some("x") => Ok((pos, 0)),
+            Some("y") => Ok((0, pos)),
+            _ => Err(Error::parse(input, dir_str, "expected `fold along x` or `fold along y`")),
+        }
+    })
+}
+
+fn get_origami_paper(input: &str) -> ParseResult<OrigamiPaper> {
+    let sections = parse::sections(input);
+    let [dots, folds] = sections[..] else {
+        return Err(parse::sections_error(input, &sections, 2, "Input should have dots and folds"));
+    };
+    let folds = get_folds(input, folds)?;
+    Ok(OrigamiPaper::new(get_dot_locations(input, dots)?, folds))
+}
+
+fn render(paper: &OrigamiPaper) -> String {
+    paper.sheet().render('#', ' ')
+}
+
+impl Solution for Day13 {
+    type Input = OrigamiPaper;
+
+    fn parse(input: &str) -> ParseResult<OrigamiPaper> {
+        get_origami_paper(input)
+    }
+
+    fn part1(origami_paper: &OrigamiPaper) -> Answer {
+        let mut origami_paper = origami_paper.clone();
+        origami_paper.fold();
+        origami_paper.dot_count().into()
+    }
+
+    fn part2(origami_paper: &OrigamiPaper) -> Answer {
+        let mut origami_paper = origami_paper.clone();
+        while origami_paper.fold() {}
+        export::save_with("day13-paper", || {
+            let mut dots: Vec<Coordinate> =
+                origami_paper.dots.iter().map(|(y, x)| Coordinate::new(x as i32, y as i32)).collect();
+            dots.sort_unstable();
+            Image::Svg(export::svg_points(&dots, 10))
+        });
+        Answer::Picture(render(&origami_paper))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use proptest::prelude::*;
+
+    use super::{get_origami_paper, render, OrigamiPaper};
+
+    /// Sheets folded in half again and again, down to `final_size` along both axes. The dots
+    /// are unfolded from the final sheet, so that like in the puzzle none lies on a fold line.
+    fn papers() -> impl Strategy<Value = OrigamiPaper> {
+        let along_x = prop::collection::vec(any::<bool>(), 1..6);
+        (along_x, (1..6u32, 1..6u32)).prop_flat_map(|(along_x, (width, height))| {
+            let mut folds = vec![];
+            let (mut x_fold, mut y_fold) = (width, height);
+            for &x in along_x.iter().rev() {
+                if x {
+                    folds.push((x_fold, 0));
+                    x_fold = 2 * x_fold + 1;
+                } else {
+                    folds.push((0, y_fold));
+                    y_fold = 2 * y_fold + 1;
+                }
+            }
+            let unfolded = prop::collection::vec(any::<bool>(), folds.len());
+            prop::collection::hash_set(((0..width, 0..height), unfolded), 1..100).prop_map(move |dots| {
+                let dots: Vec<(u32, u32)> = dots
+                    .into_iter()
+                    .map(|((x, y), unfolded)| {
+                        folds.iter().zip(unfolded).fold((x, y), |(x, y), (&fold, flip)| match (fold, flip) {
+                            ((0, pos), true) => (x, 2 * pos - y),
+                            ((pos, _), true) => (2 * pos - x, y),
+                            (_, false) => (x, y),
+                        })
+                    })
+                    .collect();
+                let mut folds = folds.clone();
+                folds.reverse();
+                OrigamiPaper::new(dots, folds)
+            })
+        })
+    }
+
+    fn fold_all(mut paper: OrigamiPaper) -> HashSet<(u32, u32)> {
+        while paper.fold() {}
+        paper.dots.iter().map(|(y, x)| (x as u32, y as u32)).collect()
+    }
+
+    #[test]
+    fn test_fold_paper() {
+        let input = "6,10
+                    0,14
+                    9,10
+                    0,3
+                    10,4
+                    4,11
+                    6,0
+                    6,12
+                    4,1
+                    0,13
+                    10,12
+                    3,4
+                    3,0
+                    8,4
+                    1,10
+                    2,14
+                    8,10
+                    9,0
+
+                    fold along y=7
+                    fold along x=5";
+        let mut origami_paper = get_origami_paper(input).unwrap();
+        origami_paper.fold();
+        assert_eq!(17, origami_paper.dot_count());
+        while origami_paper.fold() { }
+        assert_eq!("#####\n#   #\n#   #\n#   #\n#####\n", render(&origami_paper));
+    }
+
+    #[test]
+    fn test_sheet_too_big() {
+        let error = |input| get_origami_paper(input).unwrap_err().to_string();
+        assert_eq!(
+            "2:1: expected a sheet of at most 16777216 cells, found `2000000,2000000`",
+            error("0,0\n2000000,2000000\n\nfold along x=1")
+        );
+        assert_eq!(
+            "1:1: expected a sheet of at most 16777216 cells, found `4000000000,4000000000`",
+            error("4000000000,4000000000\n\nfold along y=1")
+        );
+        assert_eq!(
+            "1:1: expected a sheet of at most 16777216 cells, found `100000,100000`",
+            error("100000,100000\n\nfold along x=1")
+        );
+        assert!(get_origami_paper("4000,0\n0,4000\n\nfold along x=1").is_ok());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_folding_is_idempotent(paper in papers()) {
+            let folds = paper.folds.clone();
+            let folded = fold_all(paper);
+            prop_assert_eq!(&folded, &fold_all(OrigamiPaper::new(folded.clone(), folds)));
+        }
+    }
+}
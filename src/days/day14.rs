@@ -0,0 +1,149 @@
+use itertools::Itertools;
+
+use crate::{interner::Interner, parse, Answer, Error, ParseResult, Solution};
+
+pub struct Day14;
+
+/// A polymer as the number of times each pair of elements occurs in it, with the insertion rules.
+///
+/// Elements are interned, and the pair of `first` and `second` is at `first * elements + second`.
+#[derive(Debug, Clone)]
+pub struct Polymer {
+    elements: Interner<char>,
+    pairs: Vec<u64>,
+    /// The element inserted between each pair, at the index of the pair.
+    rules: Vec<Option<usize>>,
+}
+
+fn parse_input(input: &str) -> ParseResult<Polymer> {
+    let sections = parse::sections(input);
+    let [template_str, rules_str] = sections[..] else {
+        return Err(parse::sections_error(input, &sections, 2, "Input should have template and rules"));
+    };
+    if template_str.chars().count() < 2 {
+        return Err(Error::parse(input, template_str, "Template should have at least two elements"));
+    }
+
+    let rules: Vec<((char, char), char)> = parse::each_line(rules_str, |line| {
+        let (from_str, to_str) = parse::rule(input, line, "expected a rule as `AB -> C`")?;
+        match (from_str.chars().collect_tuple(), to_str.chars().collect_tuple()) {
+            (Some(from), Some((to,))) => Ok((from, to)),
+            (None, _) => Err(Error::parse(input, from_str, "expected a pair of elements")),
+            (_, None) => Err(Error::parse(input, to_str, "expected a single element")),
+        }
+    })?;
+
+    let mut elements = Interner::new();
+    let template: Vec<usize> = template_str.chars().map(|element| elements.intern(&element)).collect();
+    let rules: Vec<((usize, usize), usize)> = rules
+        .into_iter()
+        .map(|((first, second), inserted)| {
+            ((elements.intern(&first), elements.intern(&second)), elements.intern(&inserted))
+        })
+        .collect();
+
+    let count = elements.len();
+    let mut polymer = Polymer { elements, pairs: vec![0; count * count], rules: vec![None; count * count] };
+    for (first, second) in template.into_iter().tuple_windows() {
+        polymer.pairs[first * count + second] += 1;
+    }
+    for ((first, second), inserted) in rules {
+        polymer.rules[first * count + second] = Some(inserted);
+    }
+    Ok(polymer)
+}
+
+/// Inserts elements between the pairs `times` times. Pairs without a rule stay as they are.
+fn grow_polymer(polymer: &Polymer, times: u32) -> Vec<u64> {
+    let count = polymer.elements.len();
+    let mut pairs = polymer.pairs.clone();
+    for _ in 0..times {
+        let mut generated = vec![0; pairs.len()];
+        for (pair, &occurrences) in pairs.iter().enumerate().filter(|(_, &occurrences)| occurrences > 0) {
+            let Some(element) = polymer.rules[pair] else {
+                generated[pair] += occurrences;
+                continue;
+            };
+            let (first, second) = (pair / count, pair % count);
+            generated[first * count + element] += occurrences;
+            generated[element * count + second] += occurrences;
+        }
+        pairs = generated;
+    }
+    pairs
+}
+
+/// Every element counted twice, once in each pair it's in, but the ends of the polymer once.
+fn element_count(pairs: &[u64], count: usize) -> Vec<u64> {
+    let mut element_count = vec![0; count];
+    for (pair, occurrences) in pairs.iter().enumerate() {
+        element_count[pair / count] += occurrences;
+        element_count[pair % count] += occurrences;
+    }
+    element_count
+}
+
+pub fn count_quantity_diff_from_generated_polymer(polymer: &Polymer, times: u32) -> u64 {
+    let resulted_polymer = grow_polymer(polymer, times);
+    let count = element_count(&resulted_polymer, polymer.elements.len());
+    let present = count.iter().filter(|&&count| count > 0);
+    let most_element = present.clone().max().unwrap();
+    let least_element = present.min().unwrap();
+
+    most_element.div_ceil(2) - least_element.div_ceil(2)
+}
+
+impl Solution for Day14 {
+    type Input = Polymer;
+
+    fn parse(input: &str) -> ParseResult<Polymer> {
+        parse_input(input)
+    }
+
+    fn part1(polymer: &Polymer) -> Answer {
+        count_quantity_diff_from_generated_polymer(polymer, 10).into()
+    }
+
+    fn part2(polymer: &Polymer) -> Answer {
+        count_quantity_diff_from_generated_polymer(polymer, 40).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_quantity_diff_from_generated_polymer, parse_input};
+
+    #[test]
+    fn test_count_quantity_diff_from_generated_polymer() {
+        let input = "NNCB
+
+                    CH -> B
+                    HH -> N
+                    CB -> H
+                    NH -> C
+                    HB -> C
+                    HC -> B
+                    HN -> C
+                    NN -> C
+                    BH -> H
+                    NC -> B
+                    NB -> B
+                    BN -> B
+                    BB -> N
+                    BC -> B
+                    CC -> N
+                    CN -> C";
+        let polymer = parse_input(input).unwrap();
+        assert_eq!(1588, count_quantity_diff_from_generated_polymer(&polymer, 10));
+        assert_eq!(2188189693529, count_quantity_diff_from_generated_polymer(&polymer, 40));
+        let indented = parse_input(&input.replace("\n\n", "\n        \r\n")).unwrap();
+        assert_eq!(1588, count_quantity_diff_from_generated_polymer(&indented, 10));
+    }
+
+    #[test]
+    fn test_pairs_without_rule() {
+        // NNC grows into NBNC then NBBBNC: nothing is ever inserted in `NC`.
+        let polymer = parse_input("NNC\n\nNN -> B\nNB -> B\nBN -> B\nBB -> B").unwrap();
+        assert_eq!(3 - 1, count_quantity_diff_from_generated_polymer(&polymer, 2));
+    }
+}
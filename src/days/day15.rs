@@ -0,0 +1,136 @@
+use crate::{
+    export::{self, Image},
+    graph::{self, Search, WeightedGraph},
+    parse,
+    visualize::{self, Color, Style},
+    Answer, Grid, ParseResult, Solution,
+};
+
+pub struct Day15;
+
+fn parse_input(input: &str) -> ParseResult<Grid<u8>> {
+    parse::digit_grid(input, 1..=9, "risk level should be from 1-9")
+}
+
+/// The tile repeated `factor` times in both directions, each repetition to the right or below
+/// adding 1 to the risk levels, wrapping from 9 back to 1. The full map repeats it five times.
+pub fn tile(tile: &Grid<u8>, factor: usize) -> Grid<u8> {
+    let (rows, cols) = (tile.height(), tile.width());
+    Grid::from_fn(factor * rows, factor * cols, |(i, j)| {
+        let distance = ((i / rows + j / cols) % 9) as u8;
+        (tile[(i % rows, j % cols)] + distance - 1) % 9 + 1
+    })
+}
+
+/// Moving onto a cell costs its risk level.
+struct RiskMap<'a>(&'a Grid<u8>);
+
+impl WeightedGraph for RiskMap<'_> {
+    type Node = (usize, usize);
+    type Cost = i32;
+
+    fn edges(&self, &position: &(usize, usize)) -> impl Iterator<Item = ((usize, usize), i32)> {
+        self.0.neighbours4(position).map(|neighbour| (neighbour, self.0[neighbour] as i32))
+    }
+}
+
+/// Explored cells in green, the ones reached with the current risk in yellow.
+fn show_frontier(map: &Grid<u8>, search: &Search<(usize, usize), i32>, risk: i32) {
+    visualize::show_with(|| {
+        let cells = Grid::from_fn(map.height(), map.width(), |position| {
+            let symbol = char::from(b'0' + map[position]);
+            match search.distance(&position) {
+                Some(&reached) if reached >= risk => Style::new(symbol, Color::Yellow),
+                Some(_) => Style::new(symbol, Color::Green),
+                None => Style::new(symbol, Color::Shade(map[position] / 2)),
+            }
+        });
+        visualize::Frame { title: format!("Day 15, risk {}", risk), cells }
+    });
+}
+
+/// The risk levels in grey, brighter when riskier, with the path in red.
+fn path_image(map: &Grid<u8>, path: &[(usize, usize)]) -> Image {
+    let mut image = map.map(|&risk| [risk * 25; 3]);
+    for &position in path {
+        image[position] = export::RED;
+    }
+    Image::Ppm(image)
+}
+
+/// Saves the chosen path as `name` when exporting.
+fn find_shortest_path(map: &Grid<u8>, name: &str) -> i32 {
+    let goal = (map.height() - 1, map.width() - 1);
+    // Every step costs at least 1, so the Manhattan distance never overestimates.
+    let heuristic = |&(row, column): &(usize, usize)| (goal.0 - row + goal.1 - column) as i32;
+    let mut shown_risk = 0;
+    let search = graph::astar(&RiskMap(map), &(0, 0), heuristic, |&position, search| {
+        let risk = search.reached[&position].0;
+        if risk > shown_risk {
+            shown_risk = risk;
+            show_frontier(map, search, shown_risk);
+        }
+        position == goal
+    });
+    export::save_with(name, || path_image(map, &search.path(&goal).expect("the goal is always reachable")));
+    search.reached[&goal].0
+}
+
+pub fn find_lowest_risk_path(grid: &Grid<u8>) -> i32 {
+    find_shortest_path(grid, "day15-part1-path")
+}
+
+pub fn find_lowest_risk_path_on_extended_map(grid: &Grid<u8>) -> i32 {
+    find_shortest_path(&tile(grid, 5), "day15-part2-path")
+}
+
+impl Solution for Day15 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> ParseResult<Grid<u8>> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Grid<u8>) -> Answer {
+        find_lowest_risk_path(grid).into()
+    }
+
+    fn part2(grid: &Grid<u8>) -> Answer {
+        find_lowest_risk_path_on_extended_map(grid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_lowest_risk_path, find_lowest_risk_path_on_extended_map, parse_input};
+
+    #[test]
+    fn test_find_lowest_risk_path() {
+        let input = "1163751742
+                    1381373672
+                    2136511328
+                    3694931569
+                    7463417111
+                    1319128137
+                    1359912421
+                    3125421639
+                    1293138521
+                    2311944581";
+        assert_eq!(40, find_lowest_risk_path(&parse_input(input).unwrap()));
+    }
+
+    #[test]
+    fn test_find_lowest_risk_path_on_extended_map() {
+        let input = "1163751742
+                    1381373672
+                    2136511328
+                    3694931569
+                    7463417111
+                    1319128137
+                    1359912421
+                    3125421639
+                    1293138521
+                    2311944581";
+        assert_eq!(315, find_lowest_risk_path_on_extended_map(&parse_input(input).unwrap()));
+    }
+}
@@ -0,0 +1,321 @@
+use bitvec::prelude::*;
+use std::fmt;
+
+use crate::{Answer, Error, ParseResult, Solution};
+
+pub struct Day16;
+
+type BitStream = BitVec<Msb0, u8>;
+type Bits = BitSlice<Msb0, u8>;
+type DecodeResult<T> = Result<T, &'static str>;
+
+/// Real transmissions nest a few dozen packets deep; decoding recurses once per level.
+const MAX_DEPTH: usize = 1000;
+
+#[derive(Debug)]
+pub struct Packet {
+    version: u8,
+    type_id: u8,
+    value: u64,
+    sub_packets: Vec<Packet>,
+}
+
+impl Packet {
+    fn from_payload(payload: &str) -> ParseResult<Packet> {
+        let hex_string = payload.trim().trim_matches('\n');
+        let bitvec = Packet::hex_string_to_bitvec(payload, hex_string)?;
+        Packet::from_bit_stream(&mut bitvec.as_bitslice(), 0).map_err(|why| Error::parse(payload, hex_string, why))
+    }
+
+    fn hex_string_to_bitvec(input: &str, hex_string: &str) -> ParseResult<BitStream> {
+        if let Some((i, c)) = hex_string.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+            return Err(Error::parse(input, &hex_string[i..i + c.len_utf8()], "expected a hexadecimal digit"));
+        }
+        if hex_string.is_empty() || !hex_string.len().is_multiple_of(2) {
+            return Err(Error::parse(input, hex_string, "expected whole bytes of hexadecimal digits"));
+        }
+        Ok((0..hex_string.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex_string[i..i + 2], 16).unwrap())
+            .collect())
+    }
+
+    /// Splits the first `count` bits off `bits`.
+    fn take<'a>(bits: &mut &'a Bits, count: usize) -> DecodeResult<&'a Bits> {
+        if count > bits.len() {
+            return Err("packet is truncated");
+        }
+        let (taken, rest) = bits.split_at(count);
+        *bits = rest;
+        Ok(taken)
+    }
+
+    fn from_bit_stream(bits: &mut &Bits, depth: usize) -> DecodeResult<Packet> {
+        if depth > MAX_DEPTH {
+            return Err("packets are nested too deeply");
+        }
+        let version = Packet::take(bits, 3)?.load_be();
+        let type_id = Packet::take(bits, 3)?.load_be();
+        match type_id {
+            4 => {
+                let value = Packet::load_literal_value(bits)?;
+                Ok(Packet {version, type_id, value, sub_packets: vec![]})
+            }
+            _ => {
+                let sub_packets = Packet::load_sub_packets(bits, depth + 1)?;
+                match (type_id, sub_packets.len()) {
+                    (_, 0) => Err("operator packet has no sub-packets"),
+                    (5..=7, len) if len != 2 => Err("comparison packet should have two sub-packets"),
+                    _ => Ok(Packet {version, type_id, value: 0, sub_packets}),
+                }
+            }
+        }
+    }
+
+    fn load_literal_value(bits: &mut &Bits) -> DecodeResult<u64> {
+        let mut value_bits = BitStream::new();
+        let mut continuing = true;
+        while continuing {
+            let group = Packet::take(bits, 5)?;
+            continuing = group[0];
+            value_bits.extend_from_bitslice(&group[1..]);
+        }
+        if value_bits.len() > 64 {
+            return Err("literal value does not fit in 64 bits");
+        }
+
+        Ok(value_bits.load_be::<u64>())
+    }
+
+    fn load_sub_packets(bits: &mut &Bits, depth: usize) -> DecodeResult<Vec<Packet>> {
+        let mut packets = vec![];
+        let len_id = Packet::take(bits, 1)?[0];
+        match len_id {
+            false => {
+                let bit_len = Packet::take(bits, 15)?.load_be::<usize>();
+                let mut sub_packets_bits = Packet::take(bits, bit_len)?;
+
+                while !sub_packets_bits.is_empty() {
+                    packets.push(Packet::from_bit_stream(&mut sub_packets_bits, depth)?);
+                }
+            }
+            true => {
+                let pkg_count = Packet::take(bits, 11)?.load_be::<usize>();
+                for _ in 0..pkg_count {
+                    packets.push(Packet::from_bit_stream(bits, depth)?);
+                }
+            }
+        }
+        Ok(packets)
+    }
+
+    fn version_sum(&self) -> u32 {
+        self.sub_packets.iter()
+            .map(|sub| sub.version_sum())
+            .fold(self.version as u32,  |acc, x| acc + x)
+    }
+
+    fn evaluate(&self) -> u64 {
+        match self.type_id {
+            4 => self.value,
+            0 => self.sub_packets.iter().map(|sub| sub.evaluate()).sum(),
+            1 => self.sub_packets.iter().map(|sub| sub.evaluate()).product(),
+            2 => self.sub_packets.iter().map(|sub| sub.evaluate()).min().unwrap(),
+            3 => self.sub_packets.iter().map(|sub| sub.evaluate()).max().unwrap(),
+            type_id => {
+                assert_eq!(2, self.sub_packets.len());
+                let (sub1, sub2) = (&self.sub_packets[0], &self.sub_packets[1]);
+                match (type_id, sub1.evaluate().cmp(&sub2.evaluate())) {
+                    (5, std::cmp::Ordering::Greater) => 1,
+                    (6, std::cmp::Ordering::Less) => 1,
+                    (7, std::cmp::Ordering::Equal) => 1,
+                    _ => 0,
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Packet {
+    /// One packet per line, sub-packets indented below their operator.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut pending = vec![(self, 0)];
+        while let Some((packet, depth)) = pending.pop() {
+            let kind = match packet.type_id {
+                0 => "sum",
+                1 => "product",
+                2 => "minimum",
+                3 => "maximum",
+                4 => "literal",
+                5 => "greater than",
+                6 => "less than",
+                _ => "equal to",
+            };
+            write!(f, "{:indent$}v{} {}", "", packet.version, kind, indent = 2 * depth)?;
+            match packet.type_id {
+                4 => writeln!(f, " {}", packet.value)?,
+                _ => writeln!(f, " = {}", packet.evaluate())?,
+            }
+            pending.extend(packet.sub_packets.iter().rev().map(|sub| (sub, depth + 1)));
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day16 {
+    type Input = Packet;
+
+    fn parse(input: &str) -> ParseResult<Packet> {
+        Packet::from_payload(input)
+    }
+
+    fn part1(packet: &Packet) -> Answer {
+        packet.version_sum().into()
+    }
+
+    fn part2(packet: &Packet) -> Answer {
+        packet.evaluate().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::Packet;
+
+    fn packets() -> impl Strategy<Value = Packet> {
+        let literal = (0..8u8, any::<u64>()).prop_map(|(version, value)| Packet {
+            version,
+            type_id: 4,
+            value,
+            sub_packets: vec![],
+        });
+        literal.prop_recursive(5, 64, 4, |inner| {
+            (0..8u8, 0..4u8, prop::collection::vec(inner, 1..5)).prop_map(|(version, type_id, sub_packets)| {
+                Packet { version, type_id, value: 0, sub_packets }
+            })
+        })
+    }
+
+    fn iterative_version_sum(packet: &Packet) -> u32 {
+        let mut sum = 0;
+        let mut pending = vec![packet];
+        while let Some(packet) = pending.pop() {
+            sum += packet.version as u32;
+            pending.extend(&packet.sub_packets);
+        }
+        sum
+    }
+
+    #[test]
+    fn test_packet_from_payload() {
+        let input = "D2FE28";
+        let packet = Packet::from_payload(input).unwrap();
+        assert_eq!(6, packet.version);
+        assert_eq!(4, packet.type_id);
+        assert_eq!(2021, packet.value);
+
+        let input = "38006F45291200";
+        let packet = Packet::from_payload(input).unwrap();
+        assert_eq!(1, packet.version);
+        assert_eq!(6, packet.type_id);
+        assert_eq!(10, packet.sub_packets[0].value);
+        assert_eq!(20, packet.sub_packets[1].value);
+
+        let input = "EE00D40C823060";
+        let packet = Packet::from_payload(input).unwrap();
+        assert_eq!(7, packet.version);
+        assert_eq!(3, packet.type_id);
+        assert_eq!(1, packet.sub_packets[0].value);
+        assert_eq!(2, packet.sub_packets[1].value);
+        assert_eq!(3, packet.sub_packets[2].value);
+    }
+
+    #[test]
+    fn test_packet_version_sum() {
+        let input = "8A004A801A8002F478";
+        let packet = Packet::from_payload(input).unwrap();
+        assert_eq!(4, packet.version);
+        assert_eq!(1, packet.sub_packets[0].version);
+        assert_eq!(5, packet.sub_packets[0].sub_packets[0].version);
+        assert_eq!(
+            6,
+            packet.sub_packets[0].sub_packets[0].sub_packets[0].version
+        );
+        assert_eq!(16, packet.version_sum());
+
+        let input = "620080001611562C8802118E34";
+        assert_eq!(12, Packet::from_payload(input).unwrap().version_sum());
+
+        let input = "C0015000016115A2E0802F182340";
+        assert_eq!(23, Packet::from_payload(input).unwrap().version_sum());
+
+        let input = "A0016C880162017C3686B18A3D4780";
+        assert_eq!(31, Packet::from_payload(input).unwrap().version_sum());
+    }
+
+    #[test]
+    fn test_packet_evaluate() {
+        let input = "C200B40A82";
+        assert_eq!(3, Packet::from_payload(input).unwrap().evaluate());
+
+        let input = "04005AC33890";
+        assert_eq!(54, Packet::from_payload(input).unwrap().evaluate());
+
+        let input = "880086C3E88112";
+        assert_eq!(7, Packet::from_payload(input).unwrap().evaluate());
+
+        let input = "CE00C43D881120";
+        assert_eq!(9, Packet::from_payload(input).unwrap().evaluate());
+
+        let input = "D8005AC2A8F0";
+        assert_eq!(1, Packet::from_payload(input).unwrap().evaluate());
+
+        let input = "F600BC2D8F";
+        assert_eq!(0, Packet::from_payload(input).unwrap().evaluate());
+
+        let input = "9C005AC2F8F0";
+        assert_eq!(0, Packet::from_payload(input).unwrap().evaluate());
+
+        let input = "9C0141080250320F1802104A08";
+        assert_eq!(1, Packet::from_payload(input).unwrap().evaluate());
+    }
+
+    #[test]
+    fn test_packet_from_invalid_payload() {
+        assert_eq!(
+            "1:1: packet is truncated, found `D2FE`",
+            Packet::from_payload("D2FE").unwrap_err().to_string()
+        );
+        assert!(Packet::from_payload("D2FE2").is_err());
+        assert!(Packet::from_payload("D2FG28").is_err());
+        assert!(Packet::from_payload("").is_err());
+
+        // Operators holding a single operator, 18 bits each, around a literal.
+        let header = "000000100000000001";
+        let mut bits = header.repeat(2000) + "00010000001";
+        bits += &"0".repeat(bits.len().wrapping_neg() % 8);
+        let hex: String = bits
+            .as_bytes()
+            .chunks(4)
+            .map(|nibble| {
+                format!(
+                    "{:X}",
+                    u8::from_str_radix(std::str::from_utf8(nibble).unwrap(), 2).unwrap()
+                )
+            })
+            .collect();
+        assert!(Packet::from_payload(&hex)
+            .unwrap_err()
+            .to_string()
+            .contains("nested too deeply"));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_version_sum_matches_traversal(packet in packets()) {
+            prop_assert_eq!(iterative_version_sum(&packet), packet.version_sum());
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{
+    stream::{Lines, StreamingSolution},
+    Answer, ParseResult, Solution, Timed,
+};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+
+type SolveFn = fn(&str, &[u8]) -> ParseResult<Vec<Answer>>;
+type SolveTimedFn = fn(&str, &[u8]) -> ParseResult<Timed>;
+type SolveStreamFn = fn(Lines, &[u8]) -> ParseResult<Vec<Answer>>;
+
+pub struct Day {
+    pub number: u8,
+    solve: SolveFn,
+    solve_timed: SolveTimedFn,
+    solve_stream: Option<SolveStreamFn>,
+}
+
+impl Day {
+    pub fn name(&self) -> String {
+        format!("day{:02}", self.number)
+    }
+
+    pub fn solve(&self, input: &str, parts: &[u8]) -> ParseResult<Vec<Answer>> {
+        (self.solve)(input, parts)
+    }
+
+    pub fn solve_timed(&self, input: &str, parts: &[u8]) -> ParseResult<Timed> {
+        (self.solve_timed)(input, parts)
+    }
+
+    /// Whether the day can solve its input while reading it, see [`StreamingSolution`].
+    pub fn streams(&self) -> bool {
+        self.solve_stream.is_some()
+    }
+
+    pub fn solve_stream(&self, lines: Lines, parts: &[u8]) -> Option<ParseResult<Vec<Answer>>> {
+        self.solve_stream.map(|solve_stream| solve_stream(lines, parts))
+    }
+}
+
+macro_rules! day {
+    ($number:expr, $module:ident :: $solution:ident) => {
+        Day {
+            number: $number,
+            solve: <$module::$solution as Solution>::solve,
+            solve_timed: <$module::$solution as Solution>::solve_timed,
+            solve_stream: None,
+        }
+    };
+    ($number:expr, $module:ident :: $solution:ident, streaming) => {
+        Day {
+            solve_stream: Some(<$module::$solution as StreamingSolution>::solve_stream),
+            ..day!($number, $module::$solution)
+        }
+    };
+}
+
+pub const DAYS: [Day; 16] = [
+    day!(1, day01::Day01, streaming),
+    day!(2, day02::Day02),
+    day!(3, day03::Day03),
+    day!(4, day04::Day04),
+    day!(5, day05::Day05, streaming),
+    day!(6, day06::Day06),
+    day!(7, day07::Day07),
+    day!(8, day08::Day08),
+    day!(9, day09::Day09),
+    day!(10, day10::Day10, streaming),
+    day!(11, day11::Day11),
+    day!(12, day12::Day12),
+    day!(13, day13::Day13),
+    day!(14, day14::Day14),
+    day!(15, day15::Day15),
+    day!(16, day16::Day16),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::Verdict, read_file_to_string, Answers};
+
+    use super::{find, DAYS};
+
+    /// Runs every example saved by `aoc extract` against its expected answers.
+    #[test]
+    fn test_examples() {
+        for day in &DAYS {
+            let expected = Answers::load_examples(&Answers::examples_path(day.number)).unwrap();
+            for (example, part) in expected.keys() {
+                let input = read_file_to_string(&format!("{}-example-{}", day.name(), example)).unwrap();
+                let answer = day.solve(&input, &[part]).unwrap().remove(0);
+                assert_eq!(
+                    Verdict::Correct,
+                    expected.verify(example, part, &answer),
+                    "{} example {} part {}",
+                    day.name(),
+                    example,
+                    part
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_part() {
+        let day = find(6).unwrap();
+        let error = day.solve("3,4,3,1,2", &[1, 3]).unwrap_err();
+        assert_eq!("There is no part 3", error.to_string());
+    }
+}
@@ -0,0 +1,164 @@
+use std::{fmt, io};
+
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: String,
+        source: io::Error,
+    },
+    Parse {
+        file: Option<String>,
+        line: usize,
+        column: usize,
+        text: String,
+        message: String,
+    },
+    /// A part other than 1 or 2 was asked for.
+    UnknownPart {
+        part: u8,
+    },
+}
+
+impl Error {
+    /// Builds a parse error for `token`, which has to be a slice of `input`, so the line and
+    /// column can be worked out from where the token sits.
+    pub fn parse(input: &str, token: &str, message: impl Into<String>) -> Error {
+        let (line, column) = locate(input, token);
+        Error::Parse {
+            file: None,
+            line,
+            column,
+            text: token.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Moves an error found in a single line of a bigger input to line `number`.
+    pub fn on_line(self, number: usize) -> Error {
+        match self {
+            Error::Parse {
+                file,
+                line,
+                column,
+                text,
+                message,
+            } => Error::Parse {
+                file,
+                line: line.max(1) + number - 1,
+                column,
+                text,
+                message,
+            },
+            error => error,
+        }
+    }
+
+    pub fn in_file(self, name: &str) -> Error {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                text,
+                message,
+                ..
+            } => Error::Parse {
+                file: Some(name.to_owned()),
+                line,
+                column,
+                text,
+                message,
+            },
+            error => error,
+        }
+    }
+}
+
+/// The line and column of `token` in `input`, or `(0, 0)` when it isn't a slice of it.
+fn locate(input: &str, token: &str) -> (usize, usize) {
+    let start = input.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).wrapping_sub(start);
+    match offset.checked_add(token.len()) {
+        Some(end) if end <= input.len() && input.is_char_boundary(offset) => {}
+        _ => return (0, 0),
+    }
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "Can't open {}: {}", path, source),
+            Error::UnknownPart { part } => write!(f, "There is no part {}", part),
+            Error::Parse {
+                file,
+                line,
+                column,
+                text,
+                message,
+            } => {
+                if let Some(file) = file {
+                    write!(f, "{}:", file)?;
+                }
+                write!(f, "{}:{}: {}, found `{}`", line, column, message, text)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse { .. } | Error::UnknownPart { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+
+    #[test]
+    fn test_parse_error_location() {
+        let input = "1,2\n3,x\n";
+        let token = &input[6..7];
+        match Error::parse(input, token, "expected a number") {
+            Error::Parse {
+                line, column, text, ..
+            } => {
+                assert_eq!((2, 3), (line, column));
+                assert_eq!("x", text);
+            }
+            error => panic!("unexpected error {:?}", error),
+        }
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        let input = "forward 5\nbackward 3";
+        let error =
+            Error::parse(input, &input[10..18], "unknown direction").in_file("data/day02.txt");
+        assert_eq!(
+            "data/day02.txt:2:1: unknown direction, found `backward`",
+            error.to_string()
+        );
+        let line = "3,x";
+        let error = Error::parse(line, &line[2..], "expected a number").on_line(42);
+        assert_eq!("42:3: expected a number, found `x`", error.to_string());
+    }
+
+    #[test]
+    fn test_parse_error_outside_input() {
+        let seven = String::from("seven");
+        let error = Error::parse("5,,3", &seven, "expected a number");
+        assert_eq!("0:0: expected a number, found `seven`", error.to_string());
+        let text = "1,2\n3,x\n";
+        let error = Error::parse(&text[..5], &text[4..7], "expected a number");
+        assert_eq!("0:0: expected a number, found `3,x`", error.to_string());
+    }
+}
@@ -0,0 +1,143 @@
+//! Pulls the examples and their answers out of a saved puzzle page.
+//!
+//! Examples are the `<pre><code>` blocks of the page. The answer of each part is the last
+//! emphasised code (`<code><em>…</em></code>`) of that part's `<article>`, which is where the
+//! puzzle text states the result of the main example.
+
+use std::{fs, path::PathBuf};
+
+use crate::{input::data_dir, Answer, Answers, Error};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Page {
+    pub examples: Vec<String>,
+    /// One answer per part the page describes.
+    pub answers: Vec<Answer>,
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    decode_entities(&text)
+}
+
+/// The contents between every `open` and the following `close`.
+fn between<'a>(html: &'a str, open: &'a str, close: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    html.split(open)
+        .skip(1)
+        .filter_map(move |rest| rest.find(close).map(|end| &rest[..end]))
+}
+
+fn to_answer(text: String) -> Answer {
+    match text.parse::<i128>() {
+        Ok(number) => Answer::Number(number),
+        Err(_) => Answer::Text(text),
+    }
+}
+
+pub fn extract(html: &str) -> Page {
+    let examples = between(html, "<pre><code>", "</code></pre>")
+        .map(|block| {
+            let mut example = strip_tags(block);
+            if !example.ends_with('\n') {
+                example.push('\n');
+            }
+            example
+        })
+        .collect();
+    let answers = between(html, "<article", "</article>")
+        .filter_map(|article| {
+            between(article, "<code><em>", "</em></code>")
+                .last()
+                .map(|answer| to_answer(strip_tags(answer)))
+        })
+        .collect();
+    Page { examples, answers }
+}
+
+/// Writes `dayNN-example-K.txt` for every example and `dayNN-examples.toml` with the answers,
+/// which are assumed to belong to the first example. Returns the files written.
+pub fn save(day: u8, page: &Page) -> Result<Vec<PathBuf>, Error> {
+    let write = |path: PathBuf, content: &str| {
+        fs::write(&path, content)
+            .map(|_| path.clone())
+            .map_err(|source| Error::Io {
+                path: path.display().to_string(),
+                source,
+            })
+    };
+    let mut written = vec![];
+    for (k, example) in page.examples.iter().enumerate() {
+        let path = data_dir().join(format!("day{:02}-example-{}.txt", day, k + 1));
+        written.push(write(path, example)?);
+    }
+    if !page.examples.is_empty() && !page.answers.is_empty() {
+        let mut expected = Answers::examples();
+        for (part, answer) in (1..=2).zip(&page.answers) {
+            expected.insert(1, part, answer.clone());
+        }
+        written.push(write(Answers::examples_path(day), &expected.to_toml())?);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Answer;
+
+    use super::{extract, Page};
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 6: Lanternfish ---</h2>
+<p>For example, suppose you were given the following list:</p>
+<pre><code>3,4,3,1,2
+</code></pre>
+<p>After <code>18</code> days there would be <code>26</code> fish. After 80 days, there would be
+a total of <code><em>5934</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>345793</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>a -&gt; b &amp; <em>c</em></code></pre>
+<p>After 256 days there would be <code><em>26984457539</em></code> lanternfish.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(
+            Page {
+                examples: vec!["3,4,3,1,2\n".to_owned(), "a -> b & c\n".to_owned()],
+                answers: vec![Answer::Number(5934), Answer::Number(26984457539)],
+            },
+            extract(PAGE)
+        );
+        assert_eq!(
+            vec![Answer::Text("<ab>".to_owned())],
+            extract("<article><code><em>&lt;ab&gt;</em></code></article>").answers
+        );
+        assert_eq!(
+            Page {
+                examples: vec![],
+                answers: vec![]
+            },
+            extract("<p>nothing</p>")
+        );
+    }
+}
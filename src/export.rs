@@ -0,0 +1,231 @@
+//! Writes results as images: PGM/PPM for grids and SVG for points and segments.
+//!
+//! Like [`crate::visualize`], days hand their images to [`save_with`], which only builds and
+//! writes them while [`with_dir`] runs, which is what `--export` does.
+
+use std::{
+    cell::RefCell,
+    fmt::Write as _,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{Coordinate, Grid, Segment};
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const RED: Rgb = [220, 50, 47];
+
+/// Colours that are easy to tell apart, for labelling regions.
+pub const PALETTE: [Rgb; 8] = [
+    [230, 25, 75],
+    [60, 180, 75],
+    [255, 225, 25],
+    [0, 130, 200],
+    [245, 130, 48],
+    [145, 30, 180],
+    [70, 240, 240],
+    [240, 50, 230],
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Image {
+    Pgm(Grid<u8>),
+    Ppm(Grid<Rgb>),
+    Svg(String),
+}
+
+impl Image {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Image::Pgm(_) => "pgm",
+            Image::Ppm(_) => "ppm",
+            Image::Svg(_) => "svg",
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Image::Pgm(grid) => netpbm("P5", grid, |&level| vec![level]),
+            Image::Ppm(grid) => netpbm("P6", grid, |rgb| rgb.to_vec()),
+            Image::Svg(svg) => svg.as_bytes().to_vec(),
+        }
+    }
+}
+
+fn netpbm<T>(magic: &str, grid: &Grid<T>, bytes: impl Fn(&T) -> Vec<u8>) -> Vec<u8> {
+    let mut image = format!("{}\n{} {}\n255\n", magic, grid.width(), grid.height()).into_bytes();
+    image.extend(grid.iter().flat_map(bytes));
+    image
+}
+
+/// Grey levels stretched so that `max` is white.
+pub fn levels(grid: &Grid<u32>) -> Grid<u8> {
+    let max = grid.iter().copied().max().unwrap_or(0).max(1);
+    grid.map(|&value| (value as u64 * 255 / max as u64) as u8)
+}
+
+/// Draws every point as a `scale` wide square.
+pub fn svg_points(points: &[Coordinate], scale: u32) -> String {
+    let (low, high) = bounds(points.iter().copied());
+    let mut svg = svg_header(low, high, scale);
+    for point in points {
+        let corner = *point - low;
+        writeln!(
+            svg,
+            r#"<rect x="{}" y="{}" width="1" height="1"/>"#,
+            corner.x, corner.y
+        )
+        .unwrap();
+    }
+    svg + "</g></svg>\n"
+}
+
+pub fn svg_segments(segments: &[Segment], scale: u32) -> String {
+    let (low, high) = bounds(
+        segments
+            .iter()
+            .flat_map(|segment| [segment.from, segment.to]),
+    );
+    let mut svg = svg_header(low, high, scale);
+    for segment in segments {
+        let (from, to) = (segment.from - low, segment.to - low);
+        writeln!(
+            svg,
+            r#"<line x1="{}.5" y1="{}.5" x2="{}.5" y2="{}.5" stroke="black" stroke-width="0.5" stroke-linecap="round"/>"#,
+            from.x, from.y, to.x, to.y
+        )
+        .unwrap();
+    }
+    svg + "</g></svg>\n"
+}
+
+fn bounds(points: impl Iterator<Item = Coordinate>) -> (Coordinate, Coordinate) {
+    points.fold(
+        (
+            Coordinate::new(i32::MAX, i32::MAX),
+            Coordinate::new(i32::MIN, i32::MIN),
+        ),
+        |(low, high), point| {
+            (
+                Coordinate::new(low.x.min(point.x), low.y.min(point.y)),
+                Coordinate::new(high.x.max(point.x), high.y.max(point.y)),
+            )
+        },
+    )
+}
+
+fn svg_header(low: Coordinate, high: Coordinate, scale: u32) -> String {
+    let size = match low.x <= high.x {
+        true => high - low + Coordinate::new(1, 1),
+        false => Coordinate::new(0, 0),
+    };
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n<g fill=\"black\">\n",
+        size.x as u32 * scale,
+        size.y as u32 * scale,
+        size.x,
+        size.y
+    )
+}
+
+struct Target {
+    dir: PathBuf,
+    written: io::Result<Vec<PathBuf>>,
+}
+
+thread_local! {
+    static TARGET: RefCell<Option<Target>> = const { RefCell::new(None) };
+}
+
+/// Writes the images saved on this thread while `f` runs into `dir`, returning the files written
+/// or the first error.
+pub fn with_dir<R>(dir: &Path, f: impl FnOnce() -> R) -> (R, io::Result<Vec<PathBuf>>) {
+    let target = Target {
+        dir: dir.to_owned(),
+        written: fs::create_dir_all(dir).map(|_| vec![]),
+    };
+    let previous = TARGET.with(|current| current.replace(Some(target)));
+    let result = f();
+    let target = TARGET
+        .with(|current| current.replace(previous))
+        .expect("the target was set above");
+    (result, target.written)
+}
+
+pub fn is_active() -> bool {
+    TARGET.with(|target| target.borrow().is_some())
+}
+
+/// Builds and writes `name.<extension>`, only when exporting.
+pub fn save_with(name: &str, build: impl FnOnce() -> Image) {
+    TARGET.with(|current| {
+        let mut current = current.borrow_mut();
+        let Some(target) = current.as_mut() else {
+            return;
+        };
+        let Ok(written) = &mut target.written else {
+            return;
+        };
+        let image = build();
+        let path = target.dir.join(format!("{}.{}", name, image.extension()));
+        match fs::write(&path, image.to_bytes()) {
+            Ok(()) => written.push(path),
+            Err(why) => target.written = Err(why),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use crate::{Coordinate, Grid, Segment};
+
+    use super::{is_active, levels, save_with, svg_points, svg_segments, with_dir, Image};
+
+    #[test]
+    fn test_netpbm() {
+        let grid = Grid::from_rows(vec![vec![0, 2], vec![4, 1]]).unwrap();
+        assert_eq!(
+            b"P5\n2 2\n255\n\x00\x7f\xff\x3f".to_vec(),
+            Image::Pgm(levels(&grid)).to_bytes()
+        );
+        let colors = Grid::from_rows(vec![vec![[1, 2, 3]]]).unwrap();
+        assert_eq!(
+            b"P6\n1 1\n255\n\x01\x02\x03".to_vec(),
+            Image::Ppm(colors).to_bytes()
+        );
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = svg_points(&[Coordinate::new(2, 3), Coordinate::new(4, 3)], 10);
+        assert!(svg.contains(r#"width="30" height="10" viewBox="0 0 3 1""#));
+        assert!(svg.contains(r#"<rect x="2" y="0" width="1" height="1"/>"#));
+        let segment = Segment::new(Coordinate::new(0, 9), Coordinate::new(5, 9));
+        assert!(svg_segments(&[segment], 1).contains(r#"x1="0.5" y1="0.5" x2="5.5" y2="0.5""#));
+        assert!(svg_points(&[], 1).contains(r#"viewBox="0 0 0 0""#));
+    }
+
+    #[test]
+    fn test_with_dir() {
+        save_with("ignored", || {
+            unreachable!("nothing is built when not exporting")
+        });
+        let dir = env::temp_dir().join("aoc-export-test");
+        let (answer, written) = with_dir(&dir, || {
+            assert!(is_active());
+            save_with("paper", || Image::Svg("<svg/>".to_owned()));
+            42
+        });
+        assert_eq!(42, answer);
+        assert_eq!(vec![dir.join("paper.svg")], written.unwrap());
+        assert_eq!("<svg/>", fs::read_to_string(dir.join("paper.svg")).unwrap());
+        assert!(!is_active());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,292 @@
+//! Searches over graphs that days describe through [`Graph`] or [`WeightedGraph`].
+//!
+//! Every search returns a [`Search`] holding the distance and predecessor of every node it
+//! reached, from which [`Search::path`] rebuilds the path to any of them.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A graph whose edges all cost the same.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    fn neighbours(&self, node: &Self::Node) -> impl Iterator<Item = Self::Node>;
+}
+
+/// A graph whose edges each have a cost.
+pub trait WeightedGraph {
+    type Node: Clone + Eq + Hash;
+    type Cost: Copy + Ord + Default + Add<Output = Self::Cost>;
+
+    /// The neighbours of `node`, with the cost of getting there.
+    fn edges(&self, node: &Self::Node) -> impl Iterator<Item = (Self::Node, Self::Cost)>;
+}
+
+/// Adjacency lists.
+impl<N: Clone + Eq + Hash> Graph for HashMap<N, Vec<N>> {
+    type Node = N;
+
+    fn neighbours(&self, node: &N) -> impl Iterator<Item = N> {
+        self.get(node).into_iter().flatten().cloned()
+    }
+}
+
+/// What a search found, from its start.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    /// The nodes reached, in the order they were settled.
+    pub order: Vec<N>,
+    /// The distance of every node reached, with the node before it on the way from the start.
+    pub reached: HashMap<N, (C, Option<N>)>,
+}
+
+impl<N: Clone + Eq + Hash, C> Search<N, C> {
+    fn new(start: &N, distance: C) -> Search<N, C> {
+        Search {
+            order: vec![],
+            reached: HashMap::from([(start.clone(), (distance, None))]),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<&C> {
+        self.reached.get(node).map(|(distance, _)| distance)
+    }
+
+    pub fn predecessor(&self, node: &N) -> Option<&N> {
+        self.reached.get(node)?.1.as_ref()
+    }
+
+    /// The nodes from the start to `goal`, both included, or `None` when it wasn't reached.
+    pub fn path(&self, goal: &N) -> Option<Vec<N>> {
+        let mut path = vec![goal.clone()];
+        let mut node = self.reached.get(goal)?;
+        while let (_, Some(previous)) = node {
+            path.push(previous.clone());
+            node = &self.reached[previous];
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from `start`: distances count the edges.
+pub fn bfs<G: Graph>(graph: &G, start: &G::Node) -> Search<G::Node, usize> {
+    let mut search = Search::new(start, 0);
+    let mut queue = VecDeque::from([start.clone()]);
+    while let Some(node) = queue.pop_front() {
+        let distance = search.reached[&node].0 + 1;
+        for neighbour in graph.neighbours(&node) {
+            if let Entry::Vacant(unknown) = search.reached.entry(neighbour.clone()) {
+                unknown.insert((distance, Some(node.clone())));
+                queue.push_back(neighbour);
+            }
+        }
+        search.order.push(node);
+    }
+    search
+}
+
+/// Depth-first search from `start`: `order` is the preorder and distances are the depths in the
+/// search tree, which aren't the shortest ones.
+pub fn dfs<G: Graph>(graph: &G, start: &G::Node) -> Search<G::Node, usize> {
+    let mut search = Search::new(start, 0);
+    let mut stack = vec![(start.clone(), None)];
+    let mut settled = HashSet::new();
+    while let Some((node, predecessor)) = stack.pop() {
+        if !settled.insert(node.clone()) {
+            continue;
+        }
+        if let Some(predecessor) = predecessor {
+            let depth = search.reached[&predecessor].0 + 1;
+            search
+                .reached
+                .insert(node.clone(), (depth, Some(predecessor)));
+        }
+        let mut neighbours: Vec<G::Node> = graph.neighbours(&node).collect();
+        neighbours.reverse();
+        for neighbour in neighbours {
+            if !settled.contains(&neighbour) {
+                stack.push((neighbour, Some(node.clone())));
+            }
+        }
+        search.order.push(node);
+    }
+    search
+}
+
+/// Dijkstra's shortest paths from `start`, stopping once `stop` is true for a settled node, with
+/// the search so far, or when everything reachable is settled.
+pub fn dijkstra<G: WeightedGraph>(
+    graph: &G,
+    start: &G::Node,
+    stop: impl FnMut(&G::Node, &Search<G::Node, G::Cost>) -> bool,
+) -> Search<G::Node, G::Cost> {
+    astar(graph, start, |_| G::Cost::default(), stop)
+}
+
+/// A* from `start`, like [`dijkstra`] but trying first the nodes closer to the goal. `heuristic`
+/// estimates the remaining cost from a node to the goal, and the distances are only the shortest
+/// ones when it never overestimates it, like the Manhattan distance on a grid with costs of at
+/// least 1.
+pub fn astar<G: WeightedGraph>(
+    graph: &G,
+    start: &G::Node,
+    mut heuristic: impl FnMut(&G::Node) -> G::Cost,
+    mut stop: impl FnMut(&G::Node, &Search<G::Node, G::Cost>) -> bool,
+) -> Search<G::Node, G::Cost> {
+    let mut search = Search::new(start, G::Cost::default());
+    // Nodes don't have to be ordered, so the heap holds indices in `queued`.
+    let mut queued = vec![(start.clone(), G::Cost::default())];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(start), 0))]);
+    while let Some(Reverse((_, index))) = heap.pop() {
+        let (node, distance) = queued[index].clone();
+        if search.reached[&node].0 < distance {
+            continue;
+        }
+        search.order.push(node.clone());
+        if stop(&node, &search) {
+            break;
+        }
+        for (neighbour, cost) in graph.edges(&node) {
+            let next = distance + cost;
+            match search.reached.entry(neighbour.clone()) {
+                Entry::Occupied(known) if known.get().0 <= next => continue,
+                Entry::Occupied(mut known) => *known.get_mut() = (next, Some(node.clone())),
+                Entry::Vacant(unknown) => _ = unknown.insert((next, Some(node.clone()))),
+            }
+            heap.push(Reverse((next + heuristic(&neighbour), queued.len())));
+            queued.push((neighbour, next));
+        }
+    }
+    search
+}
+
+/// Counts the walks from `start` to `goal`, which may go through a node several times. Stepping
+/// onto a node is allowed when `enter` gives the state of the walk once there; walks stop at `goal`.
+pub fn count_paths<G: Graph, S>(
+    graph: &G,
+    start: &G::Node,
+    goal: &G::Node,
+    state: &S,
+    enter: &impl Fn(&S, &G::Node) -> Option<S>,
+) -> usize {
+    if start == goal {
+        return 1;
+    }
+    graph
+        .neighbours(start)
+        .filter_map(|neighbour| {
+            Some(count_paths(
+                graph,
+                &neighbour,
+                goal,
+                &enter(state, &neighbour)?,
+                enter,
+            ))
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::Grid;
+
+    use super::{astar, bfs, count_paths, dfs, dijkstra, Graph, WeightedGraph};
+
+    struct Costs(Grid<u32>);
+
+    impl WeightedGraph for Costs {
+        type Node = (usize, usize);
+        type Cost = u32;
+
+        fn edges(&self, &node: &(usize, usize)) -> impl Iterator<Item = ((usize, usize), u32)> {
+            self.0
+                .neighbours4(node)
+                .map(|neighbour| (neighbour, self.0[neighbour]))
+        }
+    }
+
+    fn undirected(edges: &[(u8, u8)]) -> HashMap<u8, Vec<u8>> {
+        let mut graph: HashMap<u8, Vec<u8>> = HashMap::new();
+        for &(from, to) in edges {
+            graph.entry(from).or_default().push(to);
+            graph.entry(to).or_default().push(from);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_bfs() {
+        let graph = undirected(&[(1, 2), (2, 3), (1, 4), (4, 3), (3, 5), (6, 7)]);
+        let search = bfs(&graph, &1);
+        assert_eq!(vec![1, 2, 4, 3, 5], search.order);
+        assert_eq!(Some(&3), search.distance(&5));
+        assert_eq!(Some(vec![1, 2, 3, 5]), search.path(&5));
+        assert_eq!(Some(vec![1]), search.path(&1));
+        assert_eq!(None, search.path(&6));
+        assert_eq!(vec![2, 4, 5], graph.neighbours(&3).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_dfs() {
+        let graph = undirected(&[(1, 2), (2, 3), (1, 4), (4, 3), (3, 5)]);
+        let search = dfs(&graph, &1);
+        assert_eq!(vec![1, 2, 3, 4, 5], search.order);
+        assert_eq!(Some(vec![1, 2, 3, 4]), search.path(&4));
+        assert_eq!(Some(&3), search.distance(&5));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let grid =
+            Grid::from_rows(vec![vec![1, 9, 1, 1], vec![1, 1, 1, 9], vec![9, 9, 1, 1]]).unwrap();
+        let costs = Costs(grid);
+        let goal = (2, 3);
+        let search = dijkstra(&costs, &(0, 0), |_, _| false);
+        assert_eq!(12, search.reached.len());
+        assert_eq!(Some(&(1, 2)), search.predecessor(&(2, 2)));
+        assert_eq!(Some(&5), search.distance(&goal));
+        assert_eq!(
+            Some(vec![(0, 0), (1, 0), (1, 1), (1, 2), (2, 2), (2, 3)]),
+            search.path(&goal)
+        );
+
+        let stopped = dijkstra(&costs, &(0, 0), |node, _| *node == (1, 1));
+        assert_eq!(Some(&(1, 1)), stopped.order.last());
+        assert!(stopped.order.len() < 12);
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid = Grid::from_fn(30, 30, |(row, column)| {
+            1 + ((row * 7 + column * 13) % 5) as u32
+        });
+        let costs = Costs(grid);
+        let goal = (29, 29);
+        let manhattan = |&(row, column): &(usize, usize)| (goal.0 - row + goal.1 - column) as u32;
+        let at_goal = |node: &(usize, usize), _: &_| *node == goal;
+        let fast = astar(&costs, &(0, 0), manhattan, at_goal);
+        let slow = dijkstra(&costs, &(0, 0), at_goal);
+        assert_eq!(slow.distance(&goal), fast.distance(&goal));
+        assert!(fast.order.len() <= slow.order.len());
+        let path = fast.path(&goal).unwrap();
+        let cost: u32 = path[1..].iter().map(|&position| costs.0[position]).sum();
+        assert_eq!(fast.distance(&goal), Some(&cost));
+    }
+
+    #[test]
+    fn test_count_paths() {
+        let graph = undirected(&[(1, 2), (2, 3), (1, 3), (3, 4)]);
+        let once = |visited: &Vec<u8>, node: &u8| {
+            (!visited.contains(node)).then(|| [visited.as_slice(), &[*node]].concat())
+        };
+        assert_eq!(2, count_paths(&graph, &1, &4, &vec![1], &once));
+        assert_eq!(1, count_paths(&graph, &4, &4, &vec![], &once));
+    }
+}
@@ -0,0 +1,305 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Coordinate, Error, ParseResult};
+
+/// Offsets of the up, left, right and down neighbours.
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets of all eight neighbours, diagonals included.
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row in one `Vec`, indexed with `(row, column)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut((usize, usize)) -> T) -> Grid<T> {
+        let cells = (0..height * width)
+            .map(|i| f((i / width, i % width)))
+            .collect();
+        Grid {
+            height,
+            width,
+            cells,
+        }
+    }
+
+    /// Builds a grid from its rows, or `None` when they are empty or of different lengths.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first()?.len();
+        if width == 0 || rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let height = rows.len();
+        let cells = rows.into_iter().flatten().collect();
+        Some(Grid {
+            height,
+            width,
+            cells,
+        })
+    }
+
+    /// Parses one cell per character, one row per non-empty line; indentation is ignored.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+        message: &str,
+    ) -> ParseResult<Grid<T>> {
+        let lines: Vec<&str> = input
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .collect();
+        let mut cells = vec![];
+        for line in &lines {
+            if line.chars().count() != lines[0].chars().count() {
+                return Err(Error::parse(input, line, "expected a rectangular grid"));
+            }
+            for (i, c) in line.char_indices() {
+                cells.push(
+                    cell(c)
+                        .ok_or_else(|| Error::parse(input, &line[i..i + c.len_utf8()], message))?,
+                );
+            }
+        }
+        if cells.is_empty() {
+            return Err(Error::parse(input, input, "expected a grid"));
+        }
+        let height = lines.len();
+        Ok(Grid {
+            height,
+            width: cells.len() / height,
+            cells,
+        })
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (row, column): (isize, isize)) -> bool {
+        row >= 0 && column >= 0 && (row as usize) < self.height && (column as usize) < self.width
+    }
+
+    pub fn get(&self, (row, column): (usize, usize)) -> Option<&T> {
+        match row < self.height && column < self.width {
+            true => Some(&self.cells[row * self.width + column]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, (row, column): (usize, usize)) -> Option<&mut T> {
+        match row < self.height && column < self.width {
+            true => Some(&mut self.cells[row * self.width + column]),
+            false => None,
+        }
+    }
+
+    /// Positions of the cells around `position` that are on the grid, in the order of `offsets`.
+    pub fn neighbours<'a>(
+        &self,
+        (row, column): (usize, usize),
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let (height, width) = (self.height, self.width);
+        offsets.iter().filter_map(move |(dr, dc)| {
+            let (r, c) = (
+                row.checked_add_signed(*dr)?,
+                column.checked_add_signed(*dc)?,
+            );
+            (r < height && c < width).then_some((r, c))
+        })
+    }
+
+    pub fn neighbours4(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours(position, &NEIGHBOURS_4)
+    }
+
+    pub fn neighbours8(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours(position, &NEIGHBOURS_8)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i / width, i % width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "column {} is out of the grid", column);
+        self.cells[column..].iter().step_by(self.width)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            height: self.height,
+            width: self.width,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Draws the grid with one character per cell.
+    pub fn render(&self, mut symbol: impl FnMut(&T) -> char) -> String {
+        let mut picture = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            picture.extend(row.iter().map(&mut symbol));
+            picture.push('\n');
+        }
+        picture
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid of single digits.
+    pub fn parse_digits(input: &str) -> ParseResult<Grid<u8>> {
+        Grid::parse(
+            input,
+            |c| c.to_digit(10).map(|d| d as u8),
+            "expected a digit",
+        )
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is out of the grid", position))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is out of the grid", position))
+    }
+}
+
+/// Coordinates index grids with `x` as the column and `y` as the row.
+impl<T> Index<Coordinate<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coordinate: Coordinate<usize>) -> &T {
+        &self[(coordinate.y, coordinate.x)]
+    }
+}
+
+impl<T> IndexMut<Coordinate<usize>> for Grid<T> {
+    fn index_mut(&mut self, coordinate: Coordinate<usize>) -> &mut T {
+        &mut self[(coordinate.y, coordinate.x)]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Coordinate;
+
+    use super::Grid;
+
+    #[test]
+    fn test_parse_digits() {
+        let grid = Grid::parse_digits(
+            "123
+             456",
+        )
+        .unwrap();
+        assert_eq!((2, 3), (grid.height(), grid.width()));
+        assert_eq!(6, grid[(1, 2)]);
+        assert_eq!(6, grid[Coordinate::new(2, 1)]);
+        assert_eq!(None, grid.get((2, 0)));
+        assert_eq!("123\n456\n", grid.to_string());
+        assert_eq!(
+            "2:2: expected a digit, found `x`",
+            Grid::parse_digits("123\n4x6").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "2:1: expected a rectangular grid, found `45`",
+            Grid::parse_digits("123\n45").unwrap_err().to_string()
+        );
+        assert!(Grid::parse_digits("\n").is_err());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::from_fn(3, 3, |(row, column)| row * 3 + column);
+        assert_eq!(
+            vec![(0, 1), (1, 0)],
+            grid.neighbours4((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(4, grid.neighbours4((1, 1)).count());
+        assert_eq!(3, grid.neighbours8((2, 2)).count());
+        assert_eq!(8, grid.neighbours8((1, 1)).count());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+        assert_eq!(&[3, 4], grid.row(1));
+        assert_eq!(vec![2, 4, 6], grid.column(1).copied().collect::<Vec<_>>());
+        assert_eq!(3, grid.rows().count());
+        assert_eq!(Some((2, 1)), grid.positions().last());
+        assert_eq!(
+            ".#\n.#\n.#\n",
+            grid.render(|n| if n % 2 == 0 { '#' } else { '.' })
+        );
+        assert_eq!(None, Grid::from_rows(vec![vec![1], vec![2, 3]]));
+        assert_eq!(None, Grid::<u8>::from_rows(vec![]));
+    }
+}
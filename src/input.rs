@@ -0,0 +1,202 @@
+use std::{
+    env, fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Cursor, Read},
+    path::{Path, PathBuf},
+};
+
+use flate2::read::MultiGzDecoder;
+
+use crate::{stream::Lines, Error};
+
+const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Where a puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `<name>.txt` (or `<name>.txt.gz`) in the data folder.
+    Data(String),
+    Path(PathBuf),
+    Stdin,
+    Memory(String),
+}
+
+impl InputSource {
+    /// Resolves a command line argument: `-` is stdin, anything else is a path, and no argument
+    /// falls back to the data folder entry for `name`.
+    pub fn resolve(arg: Option<&str>, name: &str) -> InputSource {
+        match arg {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::Path(PathBuf::from(path)),
+            None => InputSource::Data(name.to_owned()),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, Error> {
+        match self {
+            InputSource::Data(name) => read_path(&data_file(name)),
+            InputSource::Path(path) => read_path(path),
+            InputSource::Stdin => {
+                let mut bytes = vec![];
+                io::stdin()
+                    .lock()
+                    .read_to_end(&mut bytes)
+                    .map_err(|source| self.io_error(source))?;
+                decode(bytes).map_err(|source| self.io_error(source))
+            }
+            InputSource::Memory(input) => Ok(input.to_owned()),
+        }
+    }
+
+    /// Streams the input line by line instead of reading it all.
+    pub fn lines(&self) -> Result<Lines, Error> {
+        let reader: Box<dyn BufRead> = match self {
+            InputSource::Data(name) => open_path(&data_file(name))?,
+            InputSource::Path(path) => open_path(path)?,
+            InputSource::Stdin => Box::new(io::stdin().lock()),
+            InputSource::Memory(input) => Box::new(Cursor::new(input.clone().into_bytes())),
+        };
+        let reader = decompressed(reader).map_err(|source| self.io_error(source))?;
+        Ok(Lines::new(reader, self.to_string()))
+    }
+
+    fn io_error(&self, source: io::Error) -> Error {
+        Error::Io {
+            path: self.to_string(),
+            source,
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Data(name) => write!(f, "{}", data_file(name).display()),
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Memory(_) => write!(f, "<memory>"),
+        }
+    }
+}
+
+/// The data folder is `$AOC_DATA_DIR` when set, otherwise `data` in the working directory,
+/// falling back to the `data` folder of this crate so the binaries run from anywhere.
+pub fn data_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(DATA_DIR_VAR) {
+        return PathBuf::from(dir);
+    }
+    let local = PathBuf::from("data");
+    match local.is_dir() {
+        true => local,
+        false => Path::new(env!("CARGO_MANIFEST_DIR")).join("data"),
+    }
+}
+
+fn data_file(name: &str) -> PathBuf {
+    let plain = data_dir().join(format!("{}.txt", name));
+    let compressed = data_dir().join(format!("{}.txt.gz", name));
+    match !plain.exists() && compressed.exists() {
+        true => compressed,
+        false => plain,
+    }
+}
+
+fn read_path(path: &Path) -> Result<String, Error> {
+    let io_error = |source| Error::Io {
+        path: path.display().to_string(),
+        source,
+    };
+    let bytes = fs::read(path).map_err(io_error)?;
+    decode(bytes).map_err(io_error)
+}
+
+fn open_path(path: &Path) -> Result<Box<dyn BufRead>, Error> {
+    match File::open(path) {
+        Ok(file) => Ok(Box::new(BufReader::new(file))),
+        Err(source) => Err(Error::Io {
+            path: path.display().to_string(),
+            source,
+        }),
+    }
+}
+
+fn decompressed(mut reader: Box<dyn BufRead>) -> io::Result<Box<dyn BufRead>> {
+    match reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+        true => Ok(Box::new(BufReader::new(MultiGzDecoder::new(reader)))),
+        false => Ok(reader),
+    }
+}
+
+fn decode(bytes: Vec<u8>) -> io::Result<String> {
+    let bytes = match bytes.starts_with(&GZIP_MAGIC) {
+        true => {
+            let mut decompressed = vec![];
+            MultiGzDecoder::new(&bytes[..]).read_to_end(&mut decompressed)?;
+            decompressed
+        }
+        false => bytes,
+    };
+    String::from_utf8(bytes).map_err(|why| io::Error::new(io::ErrorKind::InvalidData, why))
+}
+
+pub fn read_file_to_string(filename: &str) -> Result<String, Error> {
+    InputSource::Data(filename.to_owned()).read()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::Write};
+
+    use flate2::{write::GzEncoder, Compression};
+
+    use super::{decode, InputSource};
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(InputSource::Stdin, InputSource::resolve(Some("-"), "day01"));
+        assert_eq!(
+            InputSource::Data("day01".to_owned()),
+            InputSource::resolve(None, "day01")
+        );
+        assert_eq!(
+            InputSource::Path("big.txt".into()),
+            InputSource::resolve(Some("big.txt"), "day01")
+        );
+    }
+
+    #[test]
+    fn test_read_memory_and_gzip() {
+        let memory = InputSource::Memory("3,4,3,1,2".to_owned());
+        assert_eq!("3,4,3,1,2", memory.read().unwrap());
+
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(b"3,4,3,1,2\n").unwrap();
+        let compressed = encoder.finish().unwrap();
+        assert_eq!("3,4,3,1,2\n", decode(compressed.clone()).unwrap());
+
+        let path = env::temp_dir().join("aoc-input-test.txt.gz");
+        fs::write(&path, compressed).unwrap();
+        assert_eq!(
+            "3,4,3,1,2\n",
+            InputSource::Path(path.clone()).read().unwrap()
+        );
+        let lines: Vec<String> = InputSource::Path(path.clone())
+            .lines()
+            .unwrap()
+            .map(|line| line.unwrap().text)
+            .collect();
+        assert_eq!(vec!["3,4,3,1,2"], lines);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let error = InputSource::Path("no/such/input.txt".into())
+            .read()
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Can't open no/such/input.txt"));
+    }
+}
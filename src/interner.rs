@@ -0,0 +1,95 @@
+//! Dense IDs for the names in an input, so that days can index `Vec`s and bitmasks with them
+//! instead of hashing the names.
+
+use std::{borrow::Borrow, collections::HashMap, hash::Hash};
+
+/// Gives every distinct name an ID, counting from 0 in the order they are first interned.
+#[derive(Debug, Clone)]
+pub struct Interner<T = String> {
+    ids: HashMap<T, usize>,
+    names: Vec<T>,
+}
+
+impl<T> Default for Interner<T> {
+    fn default() -> Interner<T> {
+        Interner {
+            ids: HashMap::new(),
+            names: vec![],
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone> Interner<T> {
+    pub fn new() -> Interner<T> {
+        Interner::default()
+    }
+
+    /// The ID of `name`, given it first if needed.
+    pub fn intern<Q>(&mut self, name: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq + Hash + ToOwned<Owned = T>,
+    {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_owned());
+        self.ids.insert(name.to_owned(), id);
+        id
+    }
+
+    /// The ID of `name`, when it was interned.
+    pub fn get<Q>(&self, name: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.ids.get(name).copied()
+    }
+
+    /// The name of `id`, which panics if no name has it.
+    pub fn name(&self, id: usize) -> &T {
+        &self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// The names with their ID, in the order of the IDs.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.names.iter().enumerate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Interner;
+
+    #[test]
+    fn test_intern() {
+        let mut caves: Interner = Interner::new();
+        assert_eq!(0, caves.intern("start"));
+        assert_eq!(1, caves.intern("A"));
+        assert_eq!(0, caves.intern("start"));
+        assert_eq!(Some(1), caves.get("A"));
+        assert_eq!(None, caves.get("end"));
+        assert_eq!("A", caves.name(1));
+        assert_eq!(2, caves.len());
+        let names: Vec<(usize, &str)> =
+            caves.iter().map(|(id, name)| (id, name.as_str())).collect();
+        assert_eq!(vec![(0, "start"), (1, "A")], names);
+
+        let mut elements = Interner::new();
+        assert_eq!(
+            [0, 1, 0],
+            ['N', 'C', 'N'].map(|element| elements.intern(&element))
+        );
+        assert_eq!(&'C', elements.name(1));
+    }
+}
@@ -1,52 +1,116 @@
-use std::cmp::Ordering;
-use std::fs::File;
-use std::io::Read;
+use std::fmt;
+use std::time::{Duration, Instant};
 
-pub fn read_file_to_string(filename: &str) -> String {
-    let data_folder = "data";
-    let input_file_path = format!("{}/{}.txt", data_folder, filename);
+pub mod answers;
+pub mod bench;
+pub mod bitgrid;
+pub mod collections;
+mod coordinate;
+pub mod days;
+mod error;
+pub mod examples;
+pub mod export;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interner;
+pub mod ocr;
+pub mod parse;
+mod segment;
+pub mod stream;
+pub mod union_find;
+pub mod visualize;
 
-    let mut file = match File::open(&input_file_path) {
-        Ok(file) => file,
-        Err(why) => panic!("Can't not open {}: {}", input_file_path, why),
-    };
-    let mut buf = String::new();
-    file.read_to_string(&mut buf).expect("Can't read input file to string.");
+pub use answers::Answers;
+pub use coordinate::Coordinate;
+pub use error::Error;
+pub use grid::Grid;
+pub use input::{read_file_to_string, InputSource};
+pub use segment::{compare_to_int, Intersection, Points, Segment};
+
+pub type ParseResult<T> = Result<T, Error>;
+
+/// Common interface of every day: parse the puzzle input once, then solve both parts on it.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> ParseResult<Self::Input>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
 
-    buf
+    fn solve(input: &str, parts: &[u8]) -> ParseResult<Vec<Answer>> {
+        Ok(Self::solve_timed(input, parts)?.answers.into_iter().map(|(answer, _)| answer).collect())
+    }
+
+    /// Same as `solve`, timing the parsing and every part separately.
+    fn solve_timed(input: &str, parts: &[u8]) -> ParseResult<Timed> {
+        let start = Instant::now();
+        let input = Self::parse(input)?;
+        let parse = start.elapsed();
+        let answers = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    1 => Self::part1(&input),
+                    2 => Self::part2(&input),
+                    part => return Err(Error::UnknownPart { part }),
+                };
+                Ok((answer, start.elapsed()))
+            })
+            .collect::<ParseResult<_>>()?;
+        Ok(Timed { parse, answers })
+    }
 }
 
-pub struct Coordinate {
-    pub x: i32,
-    pub y: i32,
+/// Answers of one run, with how long parsing and each part took.
+#[derive(Debug)]
+pub struct Timed {
+    pub parse: Duration,
+    pub answers: Vec<(Answer, Duration)>,
 }
-impl Coordinate {
-    pub fn new(x: i32, y: i32) -> Coordinate {
-        Coordinate { x, y }
-    }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// Letters drawn with `#`, see [`ocr`].
+    Picture(String),
 }
 
-pub struct Segment {
-    pub from: Coordinate,
-    pub to: Coordinate,
-    pub direction: (i32, i32),
+impl Answer {
+    /// The letters of a `Picture`, when they can be read.
+    pub fn decode(&self) -> Option<String> {
+        match self {
+            Answer::Picture(picture) => ocr::decode(picture),
+            _ => None,
+        }
+    }
 }
 
-impl Segment {
-    pub fn new(from: Coordinate, to: Coordinate) -> Segment {
-        let direction = (compare_to_int(from.x, to.x), compare_to_int(from.y, to.y));
-        Segment {
-            from,
-            to,
-            direction,
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) | Answer::Picture(text) => write!(f, "{}", text),
         }
     }
 }
 
-pub fn compare_to_int(a: i32, b: i32) -> i32 {
-    match a.cmp(&b) {
-        Ordering::Less => 1,
-        Ordering::Equal => 0,
-        Ordering::Greater => -1,
+macro_rules! impl_answer_from_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(number: $t) -> Answer {
+                Answer::Number(number as i128)
+            }
+        })*
+    };
+}
+
+impl_answer_from_number!(i32, u32, u64, usize, i128, u128);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
     }
 }
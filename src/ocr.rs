@@ -0,0 +1,74 @@
+//! Reads the block letters some puzzles draw instead of printing an answer: capitals four
+//! columns wide and six rows high, one blank column apart, drawn with `#`.
+
+const HEIGHT: usize = 6;
+const WIDTH: usize = 4;
+
+const LETTERS: [(char, [&str; HEIGHT]); 16] = [
+    ('A', [" ## ", "#  #", "#  #", "####", "#  #", "#  #"]),
+    ('B', ["### ", "#  #", "### ", "#  #", "#  #", "### "]),
+    ('C', [" ## ", "#  #", "#   ", "#   ", "#  #", " ## "]),
+    ('E', ["####", "#   ", "### ", "#   ", "#   ", "####"]),
+    ('F', ["####", "#   ", "### ", "#   ", "#   ", "#   "]),
+    ('G', [" ## ", "#  #", "#   ", "# ##", "#  #", " ###"]),
+    ('H', ["#  #", "#  #", "####", "#  #", "#  #", "#  #"]),
+    ('J', ["  ##", "   #", "   #", "   #", "#  #", " ## "]),
+    ('K', ["#  #", "# # ", "##  ", "# # ", "# # ", "#  #"]),
+    ('L', ["#   ", "#   ", "#   ", "#   ", "#   ", "####"]),
+    ('O', [" ## ", "#  #", "#  #", "#  #", "#  #", " ## "]),
+    ('P', ["### ", "#  #", "#  #", "### ", "#   ", "#   "]),
+    ('R', ["### ", "#  #", "#  #", "### ", "# # ", "#  #"]),
+    ('S', [" ###", "#   ", "#   ", " ## ", "   #", "### "]),
+    ('U', ["#  #", "#  #", "#  #", "#  #", "#  #", " ## "]),
+    ('Z', ["####", "   #", "  # ", " #  ", "#   ", "####"]),
+];
+
+/// Decodes a picture of `#` and spaces, or returns `None` when it isn't made of known letters.
+pub fn decode(picture: &str) -> Option<String> {
+    let rows: Vec<Vec<char>> = picture.lines().map(|row| row.chars().collect()).collect();
+    if rows.len() != HEIGHT {
+        return None;
+    }
+    let width = rows.iter().map(|row| row.len()).max()?;
+    let pixel = |row: usize, column: usize| {
+        rows[row]
+            .get(column)
+            .map_or(' ', |&c| if c == '#' { '#' } else { ' ' })
+    };
+    (0..width)
+        .step_by(WIDTH + 1)
+        .map(|left| {
+            let glyph: Vec<String> = (0..HEIGHT)
+                .map(|row| {
+                    (left..left + WIDTH)
+                        .map(|column| pixel(row, column))
+                        .collect()
+                })
+                .collect();
+            LETTERS
+                .iter()
+                .find(|(_, letter)| letter.iter().zip(&glyph).all(|(a, b)| a == b))
+                .map(|(c, _)| *c)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode;
+
+    #[test]
+    fn test_decode() {
+        let picture = "\
+#### #  #
+#    #  #
+###  ####
+#    #  #
+#    #  #
+#### #  #
+";
+        assert_eq!(Some("EH".to_owned()), decode(picture));
+        assert_eq!(None, decode("#####\n#   #\n#   #\n#   #\n#####\n"));
+        assert_eq!(None, decode(&picture.replace("####\n", "## #\n")));
+    }
+}
@@ -0,0 +1,204 @@
+//! Parsers for the shapes most inputs are made of.
+//!
+//! They all ignore indentation, blank lines around the input and trailing newlines. Like
+//! [`Error::parse`], they take the whole `input` along with the `text` to parse in it, which has
+//! to be a slice of `input`, so that errors point at the right line and column.
+
+use std::{ops::RangeInclusive, str::FromStr};
+
+use crate::{Error, Grid, ParseResult};
+
+/// Parses `token` with [`FromStr`].
+pub fn number<T: FromStr>(input: &str, token: &str, message: &str) -> ParseResult<T> {
+    token
+        .parse()
+        .map_err(|_| Error::parse(input, token, message))
+}
+
+/// Parses every item between commas with `item`, trimmed. Empty items are skipped, so a trailing
+/// comma is fine.
+pub fn comma_separated<'a, T>(
+    text: &'a str,
+    item: impl FnMut(&'a str) -> ParseResult<T>,
+) -> ParseResult<Vec<T>> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(item)
+        .collect()
+}
+
+/// Comma separated numbers, like `3,4,3,1,2`.
+pub fn integers<T: FromStr>(input: &str, text: &str, message: &str) -> ParseResult<Vec<T>> {
+    comma_separated(text, |token| number(input, token, message))
+}
+
+/// The non-empty lines of `text`, trimmed.
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.lines().map(str::trim).filter(|line| !line.is_empty())
+}
+
+/// Parses every non-empty line of `text` with `line`, trimmed.
+pub fn each_line<'a, T>(
+    text: &'a str,
+    line: impl FnMut(&'a str) -> ParseResult<T>,
+) -> ParseResult<Vec<T>> {
+    lines(text).map(line).collect()
+}
+
+/// The blocks of lines between blank lines, trimmed. A line with only spaces is blank too.
+pub fn sections(text: &str) -> Vec<&str> {
+    let mut sections = vec![];
+    let mut section: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some((start, end)) = section.take() {
+                sections.push(text[start..end].trim());
+            }
+        } else {
+            let start = section.map_or(offset, |(start, _)| start);
+            section = Some((start, offset + line.len()));
+        }
+        offset += line.len();
+    }
+    if let Some((start, end)) = section {
+        sections.push(text[start..end].trim());
+    }
+    sections
+}
+
+/// The error for an input whose `sections` aren't the `expected` ones. It points at the first
+/// section too many, or at the last line when sections are missing.
+pub fn sections_error(input: &str, sections: &[&str], expected: usize, message: &str) -> Error {
+    let token = match sections.get(expected) {
+        Some(extra) => lines(extra).next().unwrap_or(extra),
+        None => lines(input).last().unwrap_or(&input[input.len()..]),
+    };
+    Error::parse(input, token, message)
+}
+
+/// The two non-empty sides of `separator` in `line`, trimmed.
+pub fn split_pair<'a>(
+    input: &str,
+    line: &'a str,
+    separator: &str,
+    message: &str,
+) -> ParseResult<(&'a str, &'a str)> {
+    match line.split_once(separator) {
+        Some((left, right)) if !left.trim().is_empty() && !right.trim().is_empty() => {
+            Ok((left.trim(), right.trim()))
+        }
+        _ => Err(Error::parse(input, line, message)),
+    }
+}
+
+/// A rule like `CH -> B`, as its two sides.
+pub fn rule<'a>(input: &str, line: &'a str, message: &str) -> ParseResult<(&'a str, &'a str)> {
+    split_pair(input, line, "->", message)
+}
+
+/// An edge like `start-A`, as its two ends.
+pub fn edge<'a>(input: &str, line: &'a str, message: &str) -> ParseResult<(&'a str, &'a str)> {
+    split_pair(input, line, "-", message)
+}
+
+/// A grid of single digits, each in `digits`.
+pub fn digit_grid(input: &str, digits: RangeInclusive<u8>, message: &str) -> ParseResult<Grid<u8>> {
+    Grid::parse(
+        input,
+        |c| {
+            c.to_digit(10)
+                .map(|d| d as u8)
+                .filter(|d| digits.contains(d))
+        },
+        message,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{digit_grid, each_line, edge, integers, lines, rule, sections, sections_error};
+
+    #[test]
+    fn test_integers() {
+        let input = "  3,4, 3,1,2,\n";
+        assert_eq!(
+            vec![3, 4, 3, 1, 2],
+            integers::<u8>(input, input, "expected a timer").unwrap()
+        );
+        let input = "3,x";
+        let error = integers::<u8>(input, input, "expected a timer").unwrap_err();
+        assert_eq!("1:3: expected a timer, found `x`", error.to_string());
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "
+            7,4,9
+
+            22 13
+             8  2
+            \r
+            3 15\n";
+        let sections = sections(input);
+        assert_eq!(vec!["7,4,9", "22 13\n             8  2", "3 15"], sections);
+        assert_eq!(
+            vec!["22 13", "8  2"],
+            lines(sections[1]).collect::<Vec<_>>()
+        );
+        assert!(super::sections(" \n\n").is_empty());
+    }
+
+    #[test]
+    fn test_sections_error() {
+        let input = "1,2\n\n  a b\n  c d\n\n3\n";
+        let error = sections_error(input, &sections(input), 2, "expected two sections");
+        assert_eq!("6:1: expected two sections, found `3`", error.to_string());
+        let error = sections_error(input, &sections(input), 4, "expected four sections");
+        assert_eq!("6:1: expected four sections, found `3`", error.to_string());
+        let error = sections_error("", &[], 1, "expected a section");
+        assert_eq!("1:1: expected a section, found ``", error.to_string());
+    }
+
+    #[test]
+    fn test_rule_and_edge() {
+        let input = "CH -> B\n  start-A\nCH B";
+        let lines: Vec<&str> = lines(input).collect();
+        assert_eq!(
+            ("CH", "B"),
+            rule(input, lines[0], "expected a rule").unwrap()
+        );
+        assert_eq!(
+            ("start", "A"),
+            edge(input, lines[1], "expected an edge").unwrap()
+        );
+        let error = rule(input, lines[2], "expected a rule").unwrap_err();
+        assert_eq!("3:1: expected a rule, found `CH B`", error.to_string());
+        assert!(edge(input, "start-", "expected an edge").is_err());
+        let input = "a-b\n  c\n";
+        let edges = each_line(input, |line| edge(input, line, "expected an edge"));
+        assert_eq!(
+            "2:3: expected an edge, found `c`",
+            edges.unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_digit_grid() {
+        let input = "  12\n  30\n";
+        assert_eq!(
+            vec![1, 2, 3, 0],
+            digit_grid(input, 0..=9, "expected a digit")
+                .unwrap()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+        let error = digit_grid(input, 1..=9, "expected a digit from 1 to 9").unwrap_err();
+        assert_eq!(
+            "2:4: expected a digit from 1 to 9, found `0`",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,315 @@
+use std::cmp::Ordering;
+
+use crate::Coordinate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment {
+    pub from: Coordinate,
+    pub to: Coordinate,
+    /// Sign of each axis when walking from `from` to `to`.
+    pub direction: Coordinate,
+}
+
+/// Where two segments meet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Intersection {
+    /// A single lattice point.
+    Point(Coordinate),
+    /// A single point between lattice points, at `numerator / denominator`.
+    Fraction {
+        numerator: Coordinate<i128>,
+        denominator: i128,
+    },
+    /// Collinear segments sharing more than one point.
+    Overlap(Segment),
+}
+
+fn cross(a: Coordinate<i128>, b: Coordinate<i128>) -> i128 {
+    a.x * b.y - a.y * b.x
+}
+
+/// In `i128`, where the cross products of `i32` coordinates, and their products with a
+/// coordinate again, can't overflow.
+fn widen(coordinate: Coordinate) -> Coordinate<i128> {
+    Coordinate::new(coordinate.x as i128, coordinate.y as i128)
+}
+
+impl Segment {
+    pub fn new(from: Coordinate, to: Coordinate) -> Segment {
+        let direction = Coordinate::new(compare_to_int(from.x, to.x), compare_to_int(from.y, to.y));
+        Segment {
+            from,
+            to,
+            direction,
+        }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.from.y == self.to.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.from.x == self.to.x
+    }
+
+    /// Whether the segment runs at 45 degrees.
+    pub fn is_diagonal(&self) -> bool {
+        !self.is_point() && (self.to.x - self.from.x).abs() == (self.to.y - self.from.y).abs()
+    }
+
+    pub fn is_point(&self) -> bool {
+        self.from == self.to
+    }
+
+    /// Euclidean length.
+    pub fn length(&self) -> f64 {
+        let delta = widen(self.to) - widen(self.from);
+        ((delta.x * delta.x + delta.y * delta.y) as f64).sqrt()
+    }
+
+    /// The lowest and the highest corners of the smallest box holding the segment.
+    pub fn bounding_box(&self) -> (Coordinate, Coordinate) {
+        (
+            Coordinate::new(self.from.x.min(self.to.x), self.from.y.min(self.to.y)),
+            Coordinate::new(self.from.x.max(self.to.x), self.from.y.max(self.to.y)),
+        )
+    }
+
+    /// Whether `point` lies exactly on the segment.
+    pub fn contains(&self, point: Coordinate) -> bool {
+        let (low, high) = self.bounding_box();
+        let on_line = cross(
+            widen(point) - widen(self.from),
+            widen(self.to) - widen(self.from),
+        ) == 0;
+        on_line && low.x <= point.x && point.x <= high.x && low.y <= point.y && point.y <= high.y
+    }
+
+    /// The lattice points drawn by Bresenham's algorithm from `from` to `to`, both included.
+    /// Horizontal, vertical and diagonal segments get exactly the points they go through.
+    pub fn points(&self) -> Points {
+        let delta = widen(self.to) - widen(self.from);
+        let (dx, dy) = (delta.x.abs() as i64, delta.y.abs() as i64);
+        Points {
+            current: self.from,
+            end: self.to,
+            step: self.direction,
+            dx,
+            dy: -dy,
+            error: dx - dy,
+            remaining: dx.max(dy) as usize + 1,
+        }
+    }
+
+    pub fn intersection(&self, other: &Segment) -> Option<Intersection> {
+        if self.is_point() {
+            return other
+                .contains(self.from)
+                .then_some(Intersection::Point(self.from));
+        }
+        if other.is_point() {
+            return self
+                .contains(other.from)
+                .then_some(Intersection::Point(other.from));
+        }
+        let (p, r) = (widen(self.from), widen(self.to) - widen(self.from));
+        let (q, s) = (widen(other.from), widen(other.to) - widen(other.from));
+        let denominator = cross(r, s);
+        if denominator == 0 {
+            if cross(q - p, r) != 0 {
+                return None;
+            }
+            // Collinear: the points of a line sort the same way as along the line.
+            let low = self.from.min(self.to).max(other.from.min(other.to));
+            let high = self.from.max(self.to).min(other.from.max(other.to));
+            return match low.cmp(&high) {
+                Ordering::Less => Some(Intersection::Overlap(Segment::new(low, high))),
+                Ordering::Equal => Some(Intersection::Point(low)),
+                Ordering::Greater => None,
+            };
+        }
+        let sign = denominator.signum();
+        let (t, u, denominator) = (
+            cross(q - p, s) * sign,
+            cross(q - p, r) * sign,
+            denominator * sign,
+        );
+        if !(0..=denominator).contains(&t) || !(0..=denominator).contains(&u) {
+            return None;
+        }
+        let numerator = p * denominator + r * t;
+        match numerator.x % denominator == 0 && numerator.y % denominator == 0 {
+            true => Some(Intersection::Point(Coordinate::new(
+                (numerator.x / denominator) as i32,
+                (numerator.y / denominator) as i32,
+            ))),
+            false => Some(Intersection::Fraction {
+                numerator,
+                denominator,
+            }),
+        }
+    }
+}
+
+/// Iterator over the points of a [`Segment`].
+#[derive(Debug, Clone)]
+pub struct Points {
+    current: Coordinate,
+    end: Coordinate,
+    step: Coordinate,
+    /// In `i64`, as twice the error of segments spanning most of the `i32` range doesn't fit in
+    /// an `i32`.
+    dx: i64,
+    dy: i64,
+    error: i64,
+    remaining: usize,
+}
+
+impl Iterator for Points {
+    type Item = Coordinate;
+
+    fn next(&mut self) -> Option<Coordinate> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let point = self.current;
+        if point != self.end {
+            let doubled = 2 * self.error;
+            if doubled >= self.dy {
+                self.error += self.dy;
+                self.current.x += self.step.x;
+            }
+            if doubled <= self.dx {
+                self.error += self.dx;
+                self.current.y += self.step.y;
+            }
+        }
+        Some(point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for Points {}
+
+pub fn compare_to_int(a: i32, b: i32) -> i32 {
+    match a.cmp(&b) {
+        Ordering::Less => 1,
+        Ordering::Equal => 0,
+        Ordering::Greater => -1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Coordinate;
+
+    use super::{Intersection, Segment};
+
+    fn segment(x1: i32, y1: i32, x2: i32, y2: i32) -> Segment {
+        Segment::new(Coordinate::new(x1, y1), Coordinate::new(x2, y2))
+    }
+
+    fn points(segment: Segment) -> Vec<(i32, i32)> {
+        segment.points().map(<(i32, i32)>::from).collect()
+    }
+
+    #[test]
+    fn test_points() {
+        assert_eq!(vec![(1, 1), (1, 2), (1, 3)], points(segment(1, 1, 1, 3)));
+        assert_eq!(vec![(9, 7), (8, 8), (7, 9)], points(segment(9, 7, 7, 9)));
+        assert_eq!(
+            vec![(0, 0), (1, 0), (2, 1), (3, 1)],
+            points(segment(0, 0, 3, 1))
+        );
+        assert_eq!(
+            vec![(3, 1), (2, 1), (1, 0), (0, 0)],
+            points(segment(3, 1, 0, 0))
+        );
+        assert_eq!(vec![(4, 4)], points(segment(4, 4, 4, 4)));
+        assert_eq!(7, segment(0, 0, 6, -2).points().len());
+    }
+
+    #[test]
+    fn test_points_of_huge_segment() {
+        let points = segment(-(1 << 30), 0, 1 << 30, 1).points();
+        assert_eq!((1 << 31) + 1, points.len());
+        assert_eq!(
+            vec![(-(1 << 30), 0), (1 - (1 << 30), 0)],
+            points.take(2).map(<(i32, i32)>::from).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_measures() {
+        let diagonal = segment(5, 5, 8, 2);
+        assert!(diagonal.is_diagonal() && !diagonal.is_horizontal() && !diagonal.is_vertical());
+        assert_eq!(5.0, segment(0, 0, 3, 4).length());
+        assert_eq!(
+            (Coordinate::new(5, 2), Coordinate::new(8, 5)),
+            diagonal.bounding_box()
+        );
+        assert!(diagonal.contains(Coordinate::new(6, 4)));
+        assert!(!diagonal.contains(Coordinate::new(9, 1)));
+        assert!(!diagonal.contains(Coordinate::new(6, 5)));
+    }
+
+    #[test]
+    fn test_intersection() {
+        assert_eq!(
+            Some(Intersection::Point(Coordinate::new(2, 2))),
+            segment(0, 0, 4, 4).intersection(&segment(0, 4, 4, 0))
+        );
+        assert_eq!(
+            Some(Intersection::Fraction {
+                numerator: Coordinate::new(1, 1),
+                denominator: 2
+            }),
+            segment(0, 0, 1, 1).intersection(&segment(0, 1, 1, 0))
+        );
+        assert_eq!(None, segment(0, 0, 1, 1).intersection(&segment(3, 0, 2, 1)));
+        assert_eq!(None, segment(0, 0, 4, 0).intersection(&segment(0, 1, 4, 1)));
+        assert_eq!(
+            Some(Intersection::Overlap(segment(2, 0, 4, 0))),
+            segment(0, 0, 4, 0).intersection(&segment(6, 0, 2, 0))
+        );
+        assert_eq!(
+            Some(Intersection::Point(Coordinate::new(4, 0))),
+            segment(0, 0, 4, 0).intersection(&segment(4, 0, 6, 0))
+        );
+        assert_eq!(None, segment(0, 0, 2, 0).intersection(&segment(3, 0, 6, 0)));
+        assert_eq!(
+            Some(Intersection::Point(Coordinate::new(1, 1))),
+            segment(1, 1, 1, 1).intersection(&segment(0, 0, 2, 2))
+        );
+    }
+
+    #[test]
+    fn test_huge_segments() {
+        const MAX: i32 = i32::MAX;
+        let diagonal = segment(-MAX, -MAX, MAX, MAX);
+        assert!((diagonal.length() - 2f64.sqrt() * (2.0 * MAX as f64)).abs() < 1.0);
+        assert_eq!(
+            Some(Intersection::Point(Coordinate::new(0, 0))),
+            diagonal.intersection(&segment(-MAX, MAX, MAX, -MAX))
+        );
+        assert_eq!(
+            Some(Intersection::Point(Coordinate::new(MAX - 1, MAX - 1))),
+            segment(MAX - 2, MAX - 2, MAX, MAX).intersection(&segment(MAX - 2, MAX, MAX, MAX - 2))
+        );
+        match segment(0, 0, MAX, MAX - 1).intersection(&segment(0, 1, 1, 0)) {
+            Some(Intersection::Fraction {
+                numerator,
+                denominator,
+            }) => {
+                let max = MAX as i128;
+                assert_eq!(max * denominator, numerator.x * (2 * max - 1));
+                assert_eq!((max - 1) * denominator, numerator.y * (2 * max - 1));
+            }
+            intersection => panic!("unexpected intersection {:?}", intersection),
+        }
+    }
+}
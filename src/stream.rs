@@ -0,0 +1,121 @@
+//! Reads inputs line by line, for inputs too big to hold in memory.
+//!
+//! [`Lines`] reads from any [`BufRead`]; [`crate::InputSource::lines`] gives one for every source,
+//! decompressing gzip on the fly. Days that can solve both parts in a single pass implement
+//! [`StreamingSolution`] and run with `aoc --stream`.
+
+use std::io::BufRead;
+
+use crate::{Answer, Error, ParseResult};
+
+pub struct Line {
+    /// From 1.
+    pub number: usize,
+    /// Without the line ending.
+    pub text: String,
+}
+
+pub struct Lines {
+    reader: Box<dyn BufRead>,
+    name: String,
+    number: usize,
+}
+
+impl Lines {
+    /// `name` is where the lines come from, for the errors.
+    pub fn new(reader: impl BufRead + 'static, name: impl Into<String>) -> Lines {
+        Lines {
+            reader: Box::new(reader),
+            name: name.into(),
+            number: 0,
+        }
+    }
+
+    /// Parses every non-empty line, trimmed, with `parse`. Errors point at the line in the input,
+    /// but their columns count from the start of the trimmed text, not of the line.
+    pub fn records<T>(
+        self,
+        mut parse: impl FnMut(&str) -> ParseResult<T>,
+    ) -> impl Iterator<Item = ParseResult<T>> {
+        self.filter_map(move |line| match line {
+            Ok(line) => match line.text.trim() {
+                "" => None,
+                text => Some(parse(text).map_err(|why| why.on_line(line.number))),
+            },
+            Err(why) => Some(Err(why)),
+        })
+    }
+}
+
+impl Iterator for Lines {
+    type Item = Result<Line, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut text = String::new();
+        match self.reader.read_line(&mut text) {
+            Ok(0) => None,
+            Ok(_) => {
+                self.number += 1;
+                let end = text.trim_end_matches(['\n', '\r']).len();
+                text.truncate(end);
+                Some(Ok(Line {
+                    number: self.number,
+                    text,
+                }))
+            }
+            Err(source) => Some(Err(Error::Io {
+                path: self.name.clone(),
+                source,
+            })),
+        }
+    }
+}
+
+/// A day solving its parts in one pass over the input, holding at most a line at a time
+/// besides what the answers themselves need.
+pub trait StreamingSolution {
+    fn solve_stream(lines: Lines, parts: &[u8]) -> ParseResult<Vec<Answer>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::{Error, ParseResult};
+
+    use super::Lines;
+
+    fn number(text: &str) -> ParseResult<u32> {
+        text.parse()
+            .map_err(|_| Error::parse(text, text, "expected a number"))
+    }
+
+    #[test]
+    fn test_lines() {
+        let lines = Lines::new(Cursor::new("1\r\n\n  2\n3"), "memory");
+        let texts: Vec<(usize, String)> = lines
+            .map(|line| line.map(|line| (line.number, line.text)).unwrap())
+            .collect();
+        let expected = [(1, "1"), (2, ""), (3, "  2"), (4, "3")];
+        assert_eq!(
+            expected
+                .map(|(number, text)| (number, text.to_owned()))
+                .to_vec(),
+            texts
+        );
+    }
+
+    #[test]
+    fn test_records() {
+        let numbers: ParseResult<Vec<u32>> = Lines::new(Cursor::new("1\n\n  2\n3\n"), "memory")
+            .records(number)
+            .collect();
+        assert_eq!(vec![1, 2, 3], numbers.unwrap());
+
+        let error = Lines::new(Cursor::new("1\n2\nthree\n"), "memory")
+            .records(number)
+            .collect::<ParseResult<Vec<u32>>>()
+            .unwrap_err();
+        assert_eq!("3:1: expected a number, found `three`", error.to_string());
+    }
+}
@@ -0,0 +1,164 @@
+//! Disjoint sets, and the connected components of a [`Grid`] built on them.
+
+use crate::{bitgrid::BitGrid, Grid};
+
+/// Disjoint sets of the items `0..len`, merged with [`UnionFind::union`].
+///
+/// Union by rank and path compression keep both operations close to constant time.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFind {
+    /// Every item in a set of its own.
+    pub fn new(len: usize) -> UnionFind {
+        UnionFind {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            sizes: vec![1; len],
+            count: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// How many sets there are.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The item representing the set of `item`.
+    pub fn find(&mut self, item: usize) -> usize {
+        let mut root = item;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut item = item;
+        while self.parents[item] != root {
+            item = std::mem::replace(&mut self.parents[item], root);
+        }
+        root
+    }
+
+    /// Merges the sets of `a` and `b`, returning whether they were apart.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (parent, child) = match self.ranks[a] < self.ranks[b] {
+            true => (b, a),
+            false => (a, b),
+        };
+        self.parents[child] = parent;
+        self.sizes[parent] += self.sizes[child];
+        if self.ranks[parent] == self.ranks[child] {
+            self.ranks[parent] += 1;
+        }
+        self.count -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The size of the set of `item`.
+    pub fn size(&mut self, item: usize) -> usize {
+        let root = self.find(item);
+        self.sizes[root]
+    }
+}
+
+/// The connected components of the cells of a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    /// The component of every cell, numbered from 0 in the order of their first cell row by row.
+    pub labels: Grid<Option<usize>>,
+    /// The number of cells of every component.
+    pub sizes: Vec<usize>,
+}
+
+/// Labels the components of the cells for which `belongs` is true, joined with their up, left,
+/// right and down neighbours that belong too.
+pub fn label_components<T>(grid: &Grid<T>, mut belongs: impl FnMut(&T) -> bool) -> Components {
+    let (height, width) = (grid.height(), grid.width());
+    let members = BitGrid::from_fn(height, width, |position| belongs(&grid[position]));
+    let mut sets = UnionFind::new(grid.len());
+    for (row, column) in members.iter() {
+        let index = row * width + column;
+        if members.test((row, column + 1)) {
+            sets.union(index, index + 1);
+        }
+        if members.test((row + 1, column)) {
+            sets.union(index, index + width);
+        }
+    }
+
+    let mut roots = vec![None; grid.len()];
+    let mut sizes = vec![];
+    let labels = Grid::from_fn(height, width, |(row, column)| {
+        if !members.test((row, column)) {
+            return None;
+        }
+        let index = row * width + column;
+        let root = sets.find(index);
+        Some(*roots[root].get_or_insert_with(|| {
+            sizes.push(sets.size(root));
+            sizes.len() - 1
+        }))
+    });
+    Components { labels, sizes }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Grid;
+
+    use super::{label_components, UnionFind};
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(6, sets.count());
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(4, sets.size(2));
+        assert_eq!(1, sets.size(5));
+        assert_eq!(3, sets.count());
+        assert_eq!(6, sets.len());
+    }
+
+    #[test]
+    fn test_label_components() {
+        let grid = Grid::parse_digits(
+            "1190
+             9199
+             0990",
+        )
+        .unwrap();
+        let components = label_components(&grid, |&digit| digit != 9);
+        assert_eq!(vec![3, 1, 1, 1], components.sizes);
+        assert_eq!(
+            "00.1\n.0..\n2..3\n",
+            components.labels.render(|label| match label {
+                Some(label) => char::from(b'0' + *label as u8),
+                None => '.',
+            })
+        );
+    }
+}
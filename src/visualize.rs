@@ -0,0 +1,297 @@
+//! Shows the steps of a simulation as frames in the terminal.
+//!
+//! Days call [`show`] whenever something worth seeing happened. Nothing is drawn, nor even
+//! built, unless a [`Sink`] was installed with [`with_sink`], which is what `--visualize` does.
+
+use std::{
+    cell::RefCell,
+    fmt::Write as _,
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEventKind},
+    execute, terminal,
+};
+
+use crate::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Default,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    /// From 0 (dark) to 9 (bright), for digit grids.
+    Shade(u8),
+}
+
+impl Color {
+    fn ansi(self) -> String {
+        match self {
+            Color::Default => "\x1b[0m".to_owned(),
+            Color::Red => "\x1b[31m".to_owned(),
+            Color::Green => "\x1b[32m".to_owned(),
+            Color::Yellow => "\x1b[33m".to_owned(),
+            Color::Blue => "\x1b[34m".to_owned(),
+            Color::Magenta => "\x1b[35m".to_owned(),
+            Color::Cyan => "\x1b[36m".to_owned(),
+            Color::White => "\x1b[97m".to_owned(),
+            // The grey ramp of the 256 colours palette goes from 232 to 255.
+            Color::Shade(level) => format!("\x1b[38;5;{}m", 237 + 2 * level.min(9) as u16),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Style {
+    pub symbol: char,
+    pub color: Color,
+}
+
+impl Style {
+    pub fn new(symbol: char, color: Color) -> Style {
+        Style { symbol, color }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub title: String,
+    pub cells: Grid<Style>,
+}
+
+impl Frame {
+    pub fn new<T>(
+        title: impl Into<String>,
+        grid: &Grid<T>,
+        style: impl FnMut(&T) -> Style,
+    ) -> Frame {
+        Frame {
+            title: title.into(),
+            cells: grid.map(style),
+        }
+    }
+
+    /// The frame as text with ANSI colour codes, switching colour only when it changes.
+    pub fn to_ansi(&self) -> String {
+        let mut text = format!("{}\r\n", self.title);
+        for row in self.cells.rows() {
+            let mut current = Color::Default;
+            for style in row {
+                if style.color != current {
+                    text += &style.color.ansi();
+                    current = style.color;
+                }
+                text.push(style.symbol);
+            }
+            if current != Color::Default {
+                text += &Color::Default.ansi();
+            }
+            text += "\r\n";
+        }
+        text
+    }
+}
+
+/// Where frames go.
+pub trait Sink {
+    /// Returns false once the sink doesn't want any more frames.
+    fn show(&mut self, frame: &Frame) -> bool;
+}
+
+thread_local! {
+    static SINK: RefCell<Option<Box<dyn Sink>>> = RefCell::new(None);
+}
+
+/// Sends the frames shown on this thread while `f` runs to `sink`.
+pub fn with_sink<R>(sink: Box<dyn Sink>, f: impl FnOnce() -> R) -> R {
+    let previous = SINK.with(|current| current.replace(Some(sink)));
+    let result = f();
+    SINK.with(|current| *current.borrow_mut() = previous);
+    result
+}
+
+pub fn is_active() -> bool {
+    SINK.with(|sink| sink.borrow().is_some())
+}
+
+/// Builds and shows a frame, only when a sink is listening.
+pub fn show_with(build: impl FnOnce() -> Frame) {
+    SINK.with(|current| {
+        let mut current = current.borrow_mut();
+        if let Some(sink) = current.as_mut() {
+            if !sink.show(&build()) {
+                *current = None;
+            }
+        }
+    });
+}
+
+pub fn show<T>(title: impl Into<String>, grid: &Grid<T>, style: impl FnMut(&T) -> Style) {
+    show_with(|| Frame::new(title, grid, style));
+}
+
+/// Draws frames on the alternate screen at a steady rate.
+///
+/// Keys: space pauses and resumes, `n` or → shows the next frame while paused, `+` and `-`
+/// change the frame rate, `q` or Esc stops drawing and lets the solver finish.
+pub struct Terminal {
+    interval: Duration,
+    paused: bool,
+    last: Option<Instant>,
+}
+
+impl Terminal {
+    pub fn new(fps: u32) -> io::Result<Terminal> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Terminal {
+            interval: Duration::from_secs(1) / fps.max(1),
+            paused: false,
+            last: None,
+        })
+    }
+
+    fn draw(&self, frame: &Frame) -> io::Result<()> {
+        let mut stdout = io::stdout().lock();
+        let mut status = String::new();
+        let fps = 1.0 / self.interval.as_secs_f64();
+        write!(
+            status,
+            "{:.0} fps  [space] pause  [n] step  [+/-] speed  [q] quit",
+            fps
+        )
+        .unwrap();
+        if self.paused {
+            status += "  PAUSED";
+        }
+        execute!(
+            stdout,
+            cursor::MoveTo(0, 0),
+            terminal::Clear(terminal::ClearType::All)
+        )?;
+        write!(stdout, "{}{}\r\n", frame.to_ansi(), status)?;
+        stdout.flush()
+    }
+
+    /// Handles keys until the next frame is due. Returns false when asked to quit.
+    fn wait(&mut self) -> io::Result<bool> {
+        let due = self
+            .last
+            .map_or_else(Instant::now, |last| last + self.interval);
+        loop {
+            let timeout = match self.paused {
+                true => Duration::from_secs(3600),
+                false => due.saturating_duration_since(Instant::now()),
+            };
+            if !event::poll(timeout)? {
+                if self.paused {
+                    continue;
+                }
+                return Ok(true);
+            }
+            let key = match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => key.code,
+                _ => continue,
+            };
+            match key {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(false),
+                KeyCode::Char(' ') => self.paused = !self.paused,
+                KeyCode::Char('n') | KeyCode::Right if self.paused => return Ok(true),
+                KeyCode::Char('+') => {
+                    self.interval = (self.interval / 2).max(Duration::from_millis(1))
+                }
+                KeyCode::Char('-') => {
+                    self.interval = (self.interval * 2).min(Duration::from_secs(2))
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Sink for Terminal {
+    fn show(&mut self, frame: &Frame) -> bool {
+        let shown = self.wait().and_then(|go_on| {
+            if go_on {
+                self.draw(frame)?;
+            }
+            Ok(go_on)
+        });
+        self.last = Some(Instant::now());
+        shown.unwrap_or(false)
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::Grid;
+
+    use super::{is_active, show, with_sink, Color, Frame, Sink, Style};
+
+    struct Recorder {
+        titles: Rc<RefCell<Vec<String>>>,
+        limit: usize,
+    }
+
+    impl Sink for Recorder {
+        fn show(&mut self, frame: &Frame) -> bool {
+            self.titles.borrow_mut().push(frame.title.clone());
+            self.titles.borrow().len() < self.limit
+        }
+    }
+
+    #[test]
+    fn test_sink() {
+        let grid = Grid::from_fn(1, 1, |_| 0);
+        show("ignored", &grid, |_| {
+            unreachable!("nothing is built without a sink")
+        });
+        let titles = Rc::new(RefCell::new(vec![]));
+        let recorder = Recorder {
+            titles: titles.clone(),
+            limit: 2,
+        };
+        with_sink(Box::new(recorder), || {
+            assert!(is_active());
+            for step in 0..5 {
+                show(format!("step {}", step), &grid, |_| {
+                    Style::new('#', Color::Default)
+                });
+            }
+            assert!(!is_active());
+        });
+        assert_eq!(vec!["step 0", "step 1"], *titles.borrow());
+        assert!(!is_active());
+    }
+
+    #[test]
+    fn test_to_ansi() {
+        let grid = Grid::from_rows(vec![vec![0, 9, 9], vec![1, 1, 0]]).unwrap();
+        let frame = Frame::new("title", &grid, |&n| match n {
+            0 => Style::new('.', Color::Default),
+            1 => Style::new('#', Color::Red),
+            _ => Style::new('9', Color::Shade(9)),
+        });
+        assert_eq!(
+            "title\r\n.\x1b[38;5;255m99\x1b[0m\r\n\x1b[31m##\x1b[0m.\r\n",
+            frame.to_ansi()
+        );
+    }
+}